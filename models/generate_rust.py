@@ -11,6 +11,43 @@ def escape_rust_string(s: str) -> str:
     return s.replace("\\", "\\\\").replace('"', '\\"')
 
 
+# Letter groups that usually sound as a single phoneme
+PHONEME_GROUPS = (
+    "tch", "igh",
+    "th", "sh", "ch", "ph", "wh", "ng", "ck", "qu", "gh", "kn", "wr",
+    "ee", "ea", "oo", "ou", "ow", "ai", "ay", "oa", "oi", "oy", "au", "aw", "ie", "ei", "ey", "ue", "ew",
+)
+
+
+def estimate_phonemes(token: str) -> int:
+    """Estimate how many phonemes a token's text is pronounced with.
+
+    Letter groups from PHONEME_GROUPS count as one phoneme; every other
+    letter counts as one. A trailing silent "e" after a consonant counts as
+    none.
+    """
+    text = token.strip("^$")
+    count = 0
+    position = 0
+    while position < len(text):
+        group = next(
+            (group for group in PHONEME_GROUPS if text.startswith(group, position)),
+            None,
+        )
+        position += len(group) if group else 1
+        count += 1
+    if token.endswith("e$") and len(text) > 1 and text[-2] not in "aeiouy":
+        count -= 1
+    return count
+
+
+def token_id_range(id_to_token: list, is_kind) -> tuple:
+    """Return the (start, end) ids of the contiguous run of tokens of a kind."""
+    ids = [token_id for token_id, token in enumerate(id_to_token) if is_kind(token)]
+    assert ids == list(range(ids[0], ids[-1] + 1)), "token ids of a kind must be contiguous"
+    return ids[0], ids[-1] + 1
+
+
 def generate_rust_code(model: dict) -> str:
     """Generate Rust module code from the model."""
     id_to_token = model["id_to_token"]
@@ -24,10 +61,21 @@ def generate_rust_code(model: dict) -> str:
     lines.append("//! This module is auto-generated by `models/generate_rust.py`")
     lines.append("//! Do not edit manually.")
     lines.append("")
+    begin_tokens = token_id_range(id_to_token, lambda token: token.startswith("^"))
+    end_tokens = token_id_range(id_to_token, lambda token: token.endswith("$"))
+    middle_tokens = (end_tokens[1], len(id_to_token))
+    assert begin_tokens[0] == 0 and begin_tokens[1] == end_tokens[0]
+
+    lines.append("/// Token ids of beginning tokens (`^prefix`).")
+    lines.append(f"pub const BEGIN_TOKENS: core::ops::Range<u16> = {begin_tokens[0]}..{begin_tokens[1]};")
+    lines.append("")
+    lines.append("/// Token ids of end tokens (`suffix$`).")
+    lines.append(f"pub const END_TOKENS: core::ops::Range<u16> = {end_tokens[0]}..{end_tokens[1]};")
+    lines.append("")
     lines.append("/// Token vocabulary - maps token ID to token string.")
-    lines.append("/// Tokens 0-255 are beginning tokens (^prefix).")
-    lines.append("/// Tokens 256-511 are end tokens (suffix$).")
-    lines.append("/// Tokens 512-1023 are middle tokens.")
+    lines.append(f"/// Tokens {begin_tokens[0]}-{begin_tokens[1] - 1} are beginning tokens (^prefix).")
+    lines.append(f"/// Tokens {end_tokens[0]}-{end_tokens[1] - 1} are end tokens (suffix$).")
+    lines.append(f"/// Tokens {middle_tokens[0]}-{middle_tokens[1] - 1} are middle tokens.")
     lines.append(f"pub const TOKENS: [&str; {len(id_to_token)}] = [")
     for token in id_to_token:
         escaped = escape_rust_string(token)
@@ -35,6 +83,13 @@ def generate_rust_code(model: dict) -> str:
     lines.append("];")
     lines.append("")
 
+    lines.append("/// Estimated phoneme count of each token's text, indexed like `TOKENS`.")
+    lines.append(f"pub const TOKEN_PHONEMES: [u8; {len(id_to_token)}] = [")
+    for token in id_to_token:
+        lines.append(f"    {estimate_phonemes(token)},")
+    lines.append("];")
+    lines.append("")
+
     # Begin transitions: [(token_id, cumulative_prob_u8), ...]
     # Convert float probabilities to u8 (0-255) for efficiency
     lines.append("/// Beginning token transitions.")
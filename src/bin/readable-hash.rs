@@ -0,0 +1,107 @@
+//! Print the readable hash of a string.
+//!
+//! The string is the first positional argument, or stdin when none is
+//! given. A single trailing newline is stripped from stdin, so
+//! `echo hello | readable-hash` hashes `hello`.
+
+use readable_hash::{
+    ReadableHasher, StdHasher, english_word_hash, english_word_hash_words,
+    readable_hash_with_separator,
+};
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+const USAGE: &str =
+    "Usage: readable-hash [--mode=syllable|word] [--hasher=NAME] [--words=N] [INPUT]
+
+Hashers: std (default), shake256, sha512 and blake3, when built with the
+feature of the same name.";
+
+/// How the hash is printed.
+#[derive(Clone, Copy)]
+enum Mode {
+    /// Tokens of the word hash separated by `-`.
+    Syllable,
+    /// The word hash as one word.
+    Word,
+    /// A phrase of this many words.
+    Words(usize),
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args, io::stdin().lock()) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("readable-hash: {message}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Hash the input named by `args`, reading `stdin` if there is none.
+fn run<R: Read>(args: &[String], mut stdin: R) -> Result<String, String> {
+    let mut mode = Mode::Syllable;
+    let mut hasher = "std";
+    let mut input = None;
+    for arg in args {
+        if let Some(value) = arg.strip_prefix("--mode=") {
+            mode = match value {
+                "syllable" => Mode::Syllable,
+                "word" => Mode::Word,
+                _ => return Err(format!("unknown mode '{value}'")),
+            };
+        } else if let Some(value) = arg.strip_prefix("--hasher=") {
+            hasher = value;
+        } else if let Some(value) = arg.strip_prefix("--words=") {
+            let count = value
+                .parse()
+                .map_err(|_| format!("invalid word count '{value}'"))?;
+            mode = Mode::Words(count);
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option '{arg}'"));
+        } else if input.replace(arg.clone()).is_some() {
+            return Err("more than one input given".to_string());
+        }
+    }
+
+    let input = match input {
+        Some(input) => input,
+        None => {
+            let mut text = String::new();
+            stdin
+                .read_to_string(&mut text)
+                .map_err(|error| format!("cannot read stdin: {error}"))?;
+            if text.ends_with('\n') {
+                text.pop();
+                if text.ends_with('\r') {
+                    text.pop();
+                }
+            }
+            text
+        }
+    };
+
+    match hasher {
+        "std" => Ok(readable_hash::<StdHasher>(&input, mode)),
+        #[cfg(feature = "shake256")]
+        "shake256" => Ok(readable_hash::<readable_hash::Shake256Hasher>(&input, mode)),
+        #[cfg(feature = "sha512")]
+        "sha512" => Ok(readable_hash::<readable_hash::Sha512Hasher>(&input, mode)),
+        #[cfg(feature = "blake3")]
+        "blake3" => Ok(readable_hash::<readable_hash::Blake3Hasher>(&input, mode)),
+        _ => Err(format!("unknown or disabled hasher '{hasher}'")),
+    }
+}
+
+/// Readable hash of `input` in `mode`.
+fn readable_hash<H: ReadableHasher>(input: &str, mode: Mode) -> String {
+    match mode {
+        Mode::Syllable => readable_hash_with_separator::<H, _>(input, "-"),
+        Mode::Word => english_word_hash::<H, _>(input),
+        Mode::Words(count) => english_word_hash_words::<H, _>(input, count, " "),
+    }
+}
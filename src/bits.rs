@@ -0,0 +1,136 @@
+//! Reading bits from a [`ByteReader`].
+
+use crate::ByteReader;
+use alloc::vec::Vec;
+
+/// Bit reader that wraps a [`ByteReader`], buffering bytes and reading
+/// bits most significant first.
+///
+/// Bytes are pulled from the reader only when the requested bits need
+/// them, and bytes behind the read position are discarded, so the buffer
+/// holds only the bytes of the bits being read, however long the reader is.
+///
+/// # Examples
+/// ```
+/// use readable_hash::SliceReader;
+/// use readable_hash::bits::BitReader;
+///
+/// let mut slice_reader = SliceReader::new(&[0b1011_0010, 0xFF]);
+/// let mut bit_reader = BitReader::new(&mut slice_reader);
+/// assert_eq!(bit_reader.read_bits(3), Some(0b101));
+/// assert_eq!(bit_reader.read_u8(), Some(0b1001_0111));
+/// assert_eq!(bit_reader.bits_available(), 5);
+/// assert!(!bit_reader.has_more_bits(6));
+/// assert_eq!(bit_reader.read_bits(5), Some(0b1_1111));
+/// ```
+pub struct BitReader<'a, R: ByteReader> {
+    reader: &'a mut R,
+    buffer: Vec<u8>,
+    bit_pos: usize,
+    discarded: usize,
+    exhausted: bool,
+}
+
+impl<'a, R: ByteReader> BitReader<'a, R> {
+    /// Read bits from `reader`.
+    pub const fn new(reader: &'a mut R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            bit_pos: 0,
+            discarded: 0,
+            exhausted: false,
+        }
+    }
+
+    /// Ensure we have at least `bits` available in the buffer.
+    fn ensure_bits(&mut self, bits: usize) -> bool {
+        let consumed_bytes = self.bit_pos / 8;
+        if consumed_bytes > 0 {
+            self.buffer.drain(..consumed_bytes);
+            self.bit_pos %= 8;
+            self.discarded += consumed_bytes;
+        }
+
+        if self.exhausted {
+            return self.bits_available() >= bits;
+        }
+
+        let bytes_needed = (self.bit_pos + bits).div_ceil(8);
+        while self.buffer.len() < bytes_needed {
+            let mut byte = [0u8; 1];
+            if self.reader.read(&mut byte) == 0 {
+                self.exhausted = true;
+                break;
+            }
+            self.buffer.push(byte[0]);
+        }
+        self.bits_available() >= bits
+    }
+
+    /// Bits already pulled from the reader but not read yet.
+    ///
+    /// The reader itself may have more; use [`has_more_bits`] to find out.
+    ///
+    /// [`has_more_bits`]: Self::has_more_bits
+    pub const fn bits_available(&self) -> usize {
+        (self.buffer.len() * 8).saturating_sub(self.bit_pos)
+    }
+
+    /// Whether `bits` more bits can be read, pulling bytes from the reader
+    /// as needed.
+    pub fn has_more_bits(&mut self, bits: usize) -> bool {
+        self.ensure_bits(bits)
+    }
+
+    /// Read the next `count` bits as the low bits of a `u32`, or `None`
+    /// if fewer remain. A failed read consumes nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than 32.
+    pub fn read_bits(&mut self, count: usize) -> Option<u32> {
+        assert!(count <= 32, "cannot read {count} bits into a u32");
+        if !self.ensure_bits(count) {
+            return None;
+        }
+
+        // Bits are read MSB first, so the requested bits are the low end of
+        // the whole bytes they span once the bits after them are shifted out.
+        let start = self.bit_pos / 8;
+        let end = (self.bit_pos + count).div_ceil(8);
+        let window = self.buffer[start..end]
+            .iter()
+            .fold(0u64, |window, byte| (window << 8) | u64::from(*byte));
+        let trailing_bits = end * 8 - (self.bit_pos + count);
+        let result = (window >> trailing_bits) & ((1u64 << count) - 1);
+        self.bit_pos += count;
+        Some(result as u32)
+    }
+
+    /// Read the next 8 bits, or `None` if fewer remain.
+    pub fn read_u8(&mut self) -> Option<u8> {
+        if !self.ensure_bits(8) {
+            return None;
+        }
+
+        // Bits are read MSB first, so an unaligned byte takes the low bits
+        // of one buffered byte and the high bits of the next.
+        let byte_idx = self.bit_pos / 8;
+        let bit_idx = self.bit_pos % 8;
+        let mut result = self.buffer[byte_idx] << bit_idx;
+        if bit_idx > 0 {
+            result |= self.buffer[byte_idx + 1] >> (8 - bit_idx);
+        }
+        self.bit_pos += 8;
+        Some(result)
+    }
+
+    /// Bytes read from the underlying reader so far.
+    ///
+    /// Bytes are only read when 8 more bits are needed, so after reading
+    /// whole bytes this equals the bits read so far divided by 8.
+    pub(crate) const fn bytes_read(&self) -> usize {
+        self.discarded + self.buffer.len()
+    }
+}
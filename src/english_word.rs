@@ -2,117 +2,397 @@
 //!
 //! Data tables are generated in `english_word_data.rs`.
 
-use crate::ByteReader;
+use crate::bits::BitReader;
 use crate::english_word_data::{
-    BEGIN_TRANSITIONS, END_TRANSITION_DATA, END_TRANSITION_INDEX, TOKENS, TRANSITION_DATA,
-    TRANSITION_INDEX,
+    BEGIN_TOKENS, BEGIN_TRANSITIONS, END_TOKENS, END_TRANSITION_DATA, END_TRANSITION_INDEX,
+    TOKEN_PHONEMES, TOKENS, TRANSITION_DATA, TRANSITION_INDEX,
 };
+use crate::{ByteReader, SliceReader};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+/// Text of beginning token `index`, used as a syllable for a byte value.
+pub(crate) fn begin_syllable(index: u8) -> &'static str {
+    token_text(BEGIN_TOKENS.start + u16::from(index))
+}
+
+/// Text of end token `index`, used as a syllable for a byte value.
+pub(crate) fn end_syllable(index: u8) -> &'static str {
+    token_text(END_TOKENS.start + u16::from(index))
+}
+
+/// Byte value of a [`begin_syllable`], if `syllable` is one.
+pub(crate) fn parse_begin_syllable(syllable: &str) -> Option<u8> {
+    (0..=u8::MAX).find(|index| begin_syllable(*index) == syllable)
+}
+
+/// Byte value of an [`end_syllable`], if `syllable` is one.
+pub(crate) fn parse_end_syllable(syllable: &str) -> Option<u8> {
+    (0..=u8::MAX).find(|index| end_syllable(*index) == syllable)
+}
+
+/// Bits of entropy read for each token.
+///
+/// The transition tables store cumulative probabilities as `u8`, so every
+/// token, including the beginning and end tokens, reads one byte.
+pub const WORD_PROBABILITY_BITS: u8 = 8;
+
+/// Total text length of all tokens in the vocabulary.
+const VOCABULARY_TEXT_LEN: usize = {
+    let mut total = 0;
+    let mut i = 0;
+    while i < TOKENS.len() {
+        let token = TOKENS[i].as_bytes();
+        let mut len = token.len();
+        if token[0] == b'^' {
+            len -= 1;
+        }
+        if token[token.len() - 1] == b'$' {
+            len -= 1;
+        }
+        total += len;
+        i += 1;
+    }
+    total
+};
+
+/// Estimate the bits of entropy a word of `word_len` characters read.
+///
+/// Assumes tokens average the mean text length of the vocabulary, about
+/// 2.5 characters, and counts at least a beginning and an end token. Common
+/// tokens are shorter than rare ones, so real words tend to read somewhat
+/// more. For the exact count, use [`generate_word_counted`].
+///
+/// # Examples
+/// ```
+/// use readable_hash::english_word;
+///
+/// assert_eq!(english_word::estimated_word_bits(10), 32);
+/// ```
+pub fn estimated_word_bits(word_len: usize) -> usize {
+    let tokens = (word_len * TOKENS.len() + VOCABULARY_TEXT_LEN / 2) / VOCABULARY_TEXT_LEN;
+    tokens.max(2) * usize::from(WORD_PROBABILITY_BITS)
+}
 
 /// Find token by binary searching cumulative probabilities.
+///
+/// Picks the first entry whose cumulative value is at least `value`, or the
+/// last entry when `value` is above all of them.
 fn find_token(transitions: &[(u16, u8)], value: u8) -> u16 {
+    let index = transitions.partition_point(|(_, cumulative)| *cumulative < value);
+    transitions
+        .get(index)
+        .or_else(|| transitions.last())
+        .map_or(0, |(id, _)| *id)
+}
+
+/// Token with the widest range of values in cumulative `transitions`.
+///
+/// Ties go to the earlier token.
+fn most_likely_token(transitions: &[(u16, u8)]) -> u16 {
+    let mut best_token = transitions.first().map_or(0, |(token_id, _)| *token_id);
+    let mut best_width = 0;
+    let mut previous_cumulative: Option<u8> = None;
     for (token_id, cumulative) in transitions {
-        if *cumulative >= value {
-            return *token_id;
+        let width = match previous_cumulative {
+            Some(previous) => u16::from(cumulative.saturating_sub(previous)),
+            None => u16::from(*cumulative) + 1,
+        };
+        if width > best_width {
+            best_width = width;
+            best_token = *token_id;
         }
+        previous_cumulative = Some(*cumulative);
     }
-    transitions.last().map_or(0, |(id, _)| *id)
+    best_token
+}
+
+/// Tokens in cumulative `transitions` that some byte value selects.
+fn reachable_tokens(transitions: &[(u16, u8)]) -> impl Iterator<Item = u16> + '_ {
+    let mut previous_cumulative: Option<u8> = None;
+    transitions
+        .iter()
+        .filter_map(move |(token_id, cumulative)| {
+            let reachable = previous_cumulative.is_none_or(|previous| *cumulative > previous);
+            previous_cumulative = Some(*cumulative);
+            reachable.then_some(*token_id)
+        })
+}
+
+/// Middle-token transitions available after `token_id`.
+fn middle_transitions(token_id: u16) -> &'static [(u16, u8)] {
+    let (start, len) = TRANSITION_INDEX[token_id as usize];
+    &TRANSITION_DATA[start as usize..(start as usize + len as usize)]
+}
+
+/// End-token transitions available after `token_id`.
+fn end_transitions(token_id: u16) -> &'static [(u16, u8)] {
+    let (start, len) = END_TRANSITION_INDEX[token_id as usize];
+    &END_TRANSITION_DATA[start as usize..(start as usize + len as usize)]
 }
 
 /// Get the text for a token, stripping position markers.
-fn token_text(token_id: u16) -> &'static str {
+///
+/// # Panics
+///
+/// Panics if `token_id` is not a token of the model, i.e. not below 1024.
+pub fn token_text(token_id: u16) -> &'static str {
     let token = TOKENS[token_id as usize];
     let without_prefix = token.strip_prefix('^').unwrap_or(token);
     without_prefix.strip_suffix('$').unwrap_or(without_prefix)
 }
 
-/// Bit reader that wraps a `ByteReader`, buffering bytes and reading bits.
-struct BitReader<'a, R: ByteReader> {
-    reader: &'a mut R,
-    buffer: Vec<u8>,
-    bit_pos: usize,
-    exhausted: bool,
+/// Number of tokens in the model; valid token ids are below it.
+pub const fn token_count() -> usize {
+    TOKENS.len()
 }
 
-impl<'a, R: ByteReader> BitReader<'a, R> {
-    const fn new(reader: &'a mut R) -> Self {
-        Self {
-            reader,
-            buffer: Vec::new(),
-            bit_pos: 0,
-            exhausted: false,
-        }
-    }
+/// Get the text for a token like [`token_text`], or `None` if `token_id`
+/// is not a token of the model.
+///
+/// # Examples
+/// ```
+/// use readable_hash::english_word;
+///
+/// assert_eq!(english_word::token_text_for(0), Some(english_word::token_text(0)));
+/// assert_eq!(english_word::token_text_for(english_word::token_count() as u16), None);
+/// ```
+pub fn token_text_for(token_id: u16) -> Option<&'static str> {
+    (usize::from(token_id) < token_count()).then(|| token_text(token_id))
+}
 
-    /// Ensure we have at least `bits` available in the buffer.
-    fn ensure_bits(&mut self, bits: usize) -> bool {
-        if self.exhausted {
-            return self.bits_available() >= bits;
-        }
+/// Concatenate the texts of `tokens`.
+fn join_tokens(tokens: &[u16]) -> String {
+    tokens
+        .iter()
+        .map(|token_id| token_text(*token_id))
+        .collect()
+}
 
-        let bytes_needed = (self.bit_pos + bits).div_ceil(8);
-        while self.buffer.len() < bytes_needed {
-            let mut byte = [0u8; 1];
-            if self.reader.read(&mut byte) == 0 {
-                self.exhausted = true;
-                break;
-            }
-            self.buffer.push(byte[0]);
-        }
-        self.bits_available() >= bits
-    }
+/// Generate an English-like word with a minimum target length.
+///
+/// The output always ends with an end token. If it cannot exactly match
+/// the target length, it will stop at the shortest possible length
+/// that is >= `target_len` when such an end token is available.
+pub fn generate_word_with_target_len<R: ByteReader>(reader: &mut R, target_len: usize) -> String {
+    target_len_tokens(reader, target_len, usize::MAX, text_len)
+        .into_iter()
+        .map(|(token_id, _)| token_text(token_id))
+        .collect()
+}
 
-    const fn bits_available(&self) -> usize {
-        (self.buffer.len() * 8).saturating_sub(self.bit_pos)
-    }
+/// Generate an English-like word of at most `max_len` bytes.
+///
+/// A middle token is added only if the word still fits with the shortest
+/// end token after it. When the next middle token would not fit, its byte
+/// selects the end token instead; an end token that does not fit is
+/// replaced by the shortest one. The beginning token is always kept, so if
+/// it and the shortest end token together exceed `max_len`, the word
+/// overflows `max_len` rather than being cut mid-token.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE];
+/// let word = english_word::generate_word_with_max_len(&mut SliceReader::new(&entropy), 8);
+/// assert!(word.len() <= 8);
+/// ```
+pub fn generate_word_with_max_len<R: ByteReader>(reader: &mut R, max_len: usize) -> String {
+    let mut bit_reader = BitReader::new(reader);
+    let Some(begin_value) = bit_reader.read_u8() else {
+        return String::new();
+    };
+    let mut current_token = find_token(&BEGIN_TRANSITIONS, begin_value);
+    let mut result = token_text(current_token).to_string();
 
-    fn read_u8(&mut self) -> Option<u8> {
-        if !self.ensure_bits(8) {
-            return None;
+    let mut end_value = None;
+    while bit_reader.has_more_bits(8) {
+        let trans = middle_transitions(current_token);
+        if trans.is_empty() {
+            break;
         }
+        let Some(value) = bit_reader.read_u8() else {
+            break;
+        };
+        let next_token = find_token(trans, value);
+        if result.len() + text_len(next_token) + shortest_end_len(next_token) > max_len {
+            end_value = Some(value);
+            break;
+        }
+        result.push_str(token_text(next_token));
+        current_token = next_token;
+    }
 
-        let mut result: u8 = 0;
-        for _ in 0..8 {
-            let byte_idx = self.bit_pos / 8;
-            let bit_idx = self.bit_pos % 8;
-            let bit = (self.buffer[byte_idx] >> (7 - bit_idx)) & 1;
-            result = (result << 1) | bit;
-            self.bit_pos += 1;
+    let trans = end_transitions(current_token);
+    if !trans.is_empty() {
+        let value = end_value.or_else(|| bit_reader.read_u8()).unwrap_or(0);
+        let mut end_token = find_token(trans, value);
+        if result.len() + text_len(end_token) > max_len {
+            end_token = reachable_tokens(trans)
+                .min_by_key(|token_id| text_len(*token_id))
+                .unwrap_or(end_token);
         }
-        Some(result)
+        result.push_str(token_text(end_token));
     }
+    result
+}
+
+/// Length of the shortest end token that can follow `token_id`, or `0`
+/// if none can.
+fn shortest_end_len(token_id: u16) -> usize {
+    reachable_tokens(end_transitions(token_id))
+        .map(text_len)
+        .min()
+        .unwrap_or(0)
+}
 
-    fn has_more(&mut self) -> bool {
-        self.ensure_bits(8)
+/// Generate an English-like word of `min_len` to `max_len` bytes.
+///
+/// Works like [`generate_word_with_target_len`] with `min_len` as the
+/// target, but never picks a middle token that takes the word past
+/// `max_len`. When the entropy selects a token that is too long, the first
+/// middle token that fits is used instead. The bounds hold whenever the
+/// model has tokens that fit them; otherwise the word may fall short of
+/// `min_len`, e.g. when no end token fits. Returns an empty string when
+/// `min_len > max_len`.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE];
+/// let word = english_word::generate_word_in_range(&mut SliceReader::new(&entropy), 5, 7);
+/// assert!((5..=7).contains(&word.len()));
+/// ```
+pub fn generate_word_in_range<R: ByteReader>(
+    reader: &mut R,
+    min_len: usize,
+    max_len: usize,
+) -> String {
+    if min_len > max_len {
+        return String::new();
     }
+    target_len_tokens(reader, min_len, max_len, text_len)
+        .into_iter()
+        .map(|(token_id, _)| token_text(token_id))
+        .collect()
 }
 
-/// Generate an English-like word with a minimum target length.
+/// Explain [`generate_word_with_target_len`] token by token.
 ///
-/// The output always ends with an end token. If it cannot exactly match
-/// the target length, it will stop at the shortest possible length
-/// that is >= `target_len` when such an end token is available.
-pub fn generate_word_with_target_len<R: ByteReader>(reader: &mut R, target_len: usize) -> String {
+/// Returns each token's text with the entropy byte that selected it, in
+/// order; joining the texts gives the generated word. Every token consumes
+/// exactly one byte. When the reader runs dry before the end token, the end
+/// token is chosen as if the byte were `0`, and `0` is reported. An end
+/// token that was swapped for a longer one to reach `target_len` is
+/// reported with the byte that was read.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF];
+/// let explained = english_word::explain_word_with_target_len(&mut SliceReader::new(&entropy), 4);
+/// let word: String = explained.iter().map(|(text, _)| *text).collect();
+/// assert_eq!(
+///     word,
+///     english_word::generate_word_with_target_len(&mut SliceReader::new(&entropy), 4)
+/// );
+/// assert_eq!(explained[0].1, 0xDE);
+/// ```
+pub fn explain_word_with_target_len<R: ByteReader>(
+    reader: &mut R,
+    target_len: usize,
+) -> Vec<(&'static str, u8)> {
+    target_len_tokens(reader, target_len, usize::MAX, text_len)
+        .into_iter()
+        .map(|(token_id, value)| (token_text(token_id), value))
+        .collect()
+}
+
+/// Generate an English-like word of at least `target_phonemes` phonemes.
+///
+/// Works like [`generate_word_with_target_len`], but measures the word in
+/// estimated phonemes instead of characters, which tracks how long a word
+/// takes to say. Returns the word with its estimated phoneme count. The
+/// estimate comes from a per-token table generated with the model, so it is
+/// approximate: `"th"` counts as one phoneme and a silent final `"e"` as
+/// none. The count falls short of the target only when entropy runs out or
+/// the model cannot continue the word.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE];
+/// let (word, phonemes) = english_word::generate_word_phonemes(&mut SliceReader::new(&entropy), 4);
+/// assert!(!word.is_empty());
+/// assert!(phonemes >= 4);
+/// ```
+pub fn generate_word_phonemes<R: ByteReader>(
+    reader: &mut R,
+    target_phonemes: usize,
+) -> (String, usize) {
+    let tokens = target_len_tokens(reader, target_phonemes, usize::MAX, token_phonemes);
+    let word = tokens
+        .iter()
+        .map(|(token_id, _)| token_text(*token_id))
+        .collect();
+    let phonemes = tokens
+        .iter()
+        .map(|(token_id, _)| token_phonemes(*token_id))
+        .sum();
+    (word, phonemes)
+}
+
+/// Length of a token's text in bytes.
+fn text_len(token_id: u16) -> usize {
+    token_text(token_id).len()
+}
+
+/// Estimated phoneme count of a token.
+fn token_phonemes(token_id: u16) -> usize {
+    usize::from(TOKEN_PHONEMES[token_id as usize])
+}
+
+/// Token ids chosen by [`generate_word_with_target_len`], each with the
+/// entropy byte used to pick it.
+///
+/// `measure` gives the size of each token, so the target can be in
+/// characters or in phonemes. Middle tokens after which the word cannot
+/// end within `max_len` are swapped for the first one that fits; when none
+/// fits, the word ends early.
+fn target_len_tokens<R: ByteReader>(
+    reader: &mut R,
+    target_len: usize,
+    max_len: usize,
+    measure: fn(u16) -> usize,
+) -> Vec<(u16, u8)> {
     let mut bit_reader = BitReader::new(reader);
-    let mut result = String::new();
+    let mut tokens = Vec::new();
 
     // Select beginning token
     let Some(begin_value) = bit_reader.read_u8() else {
-        return String::new();
+        return tokens;
     };
     let first_token = find_token(&BEGIN_TRANSITIONS, begin_value);
-    result.push_str(token_text(first_token));
+    tokens.push((first_token, begin_value));
     let mut current_token = first_token;
-    let mut current_len = result.len();
+    let mut current_len = measure(first_token);
+    let in_range = |len: usize| len >= target_len && len <= max_len;
 
     loop {
-        let (end_start, end_len) = END_TRANSITION_INDEX[current_token as usize];
-        if end_len > 0 {
-            let end_trans =
-                &END_TRANSITION_DATA[end_start as usize..(end_start as usize + end_len as usize)];
+        let end_trans = end_transitions(current_token);
+        if !end_trans.is_empty() {
             let mut can_reach_target = current_len >= target_len;
             if !can_reach_target {
                 for (end_id, _) in end_trans {
-                    if current_len + token_text(*end_id).len() >= target_len {
+                    if in_range(current_len + measure(*end_id)) {
                         can_reach_target = true;
                         break;
                     }
@@ -122,36 +402,55 @@ pub fn generate_word_with_target_len<R: ByteReader>(reader: &mut R, target_len:
             if can_reach_target {
                 let value = bit_reader.read_u8().unwrap_or(0);
                 let mut end_token = find_token(end_trans, value);
-                if current_len + token_text(end_token).len() < target_len {
+                if !in_range(current_len + measure(end_token)) {
                     if let Some((end_id, _)) = end_trans
                         .iter()
-                        .find(|(end_id, _)| current_len + token_text(*end_id).len() >= target_len)
+                        .find(|(end_id, _)| in_range(current_len + measure(*end_id)))
                     {
                         end_token = *end_id;
                     } else if let Some((end_id, _)) = end_trans.last() {
                         end_token = *end_id;
                     }
                 }
-                result.push_str(token_text(end_token));
+                tokens.push((end_token, value));
                 break;
             }
         }
 
-        let (start, len) = TRANSITION_INDEX[current_token as usize];
-        if len == 0 {
+        let trans = middle_transitions(current_token);
+        if trans.is_empty() {
             break;
         }
         let Some(value) = bit_reader.read_u8() else {
             break;
         };
-        let trans = &TRANSITION_DATA[start as usize..(start as usize + len as usize)];
-        let next_token = find_token(trans, value);
-        result.push_str(token_text(next_token));
+        // A middle token fits if the word can still end within `max_len`
+        let fits = |token_id: u16| {
+            let shortest_end = reachable_tokens(end_transitions(token_id))
+                .map(measure)
+                .min()
+                .unwrap_or(0);
+            current_len.saturating_add(measure(token_id) + shortest_end) <= max_len
+        };
+        let mut next_token = find_token(trans, value);
+        if !fits(next_token) {
+            let fitting = reachable_tokens(trans).find(|token_id| fits(*token_id));
+            let Some(fitting) = fitting else {
+                if let Some(end_token) = reachable_tokens(end_trans)
+                    .find(|token_id| current_len + measure(*token_id) <= max_len)
+                {
+                    tokens.push((end_token, value));
+                }
+                break;
+            };
+            next_token = fitting;
+        }
+        tokens.push((next_token, value));
         current_token = next_token;
-        current_len = result.len();
+        current_len += measure(next_token);
     }
 
-    result
+    tokens
 }
 
 /// Generate an English-like word from a `ByteReader`.
@@ -160,50 +459,1463 @@ pub fn generate_word_with_target_len<R: ByteReader>(reader: &mut R, target_len:
 /// is exhausted. The word consists of a beginning token, zero or more
 /// middle tokens, and an end token.
 ///
+/// A single byte is enough for a word: it selects the beginning token, and
+/// the end token, if the model has one after it, falls back to the most
+/// likely one. Only an empty reader gives an empty string.
+///
 /// # Panics
 ///
 /// This function will not panic under normal usage. Internal assertions
 /// are guaranteed by the function's control flow.
 pub fn generate_word<R: ByteReader>(reader: &mut R) -> String {
+    generate_word_status(reader).0
+}
+
+/// Generate an English-like word with its first letter capitalized.
+///
+/// Generates the same word as [`generate_word`] and uppercases its first
+/// ASCII letter, skipping anything before it. An empty word stays empty.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE];
+/// let word = english_word::generate_word_capitalized(&mut SliceReader::new(&entropy));
+/// assert_eq!(word, "Syruleolynther");
+/// ```
+pub fn generate_word_capitalized<R: ByteReader>(reader: &mut R) -> String {
+    WordGenerator::builder()
+        .capitalize(true)
+        .build()
+        .generate(reader)
+}
+
+/// Word generator configured with a [`WordGeneratorBuilder`].
+///
+/// Collects the length and casing options of the `generate_word_*`
+/// functions in one place.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+/// use english_word::WordGenerator;
+///
+/// let generator = WordGenerator::builder().min_len(4).max_len(10).capitalize(true).build();
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE];
+/// let word = generator.generate(&mut SliceReader::new(&entropy));
+/// assert!((4..=10).contains(&word.len()));
+/// assert!(word.starts_with(char::is_uppercase));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WordGenerator<'a> {
+    min_len: usize,
+    max_len: Option<usize>,
+    capitalize: bool,
+    deny_list: &'a [&'a str],
+}
+
+/// Words generated after the first when it is on the deny list.
+const DENIED_WORD_RETRIES: usize = 8;
+
+impl<'a> WordGenerator<'a> {
+    /// Start configuring a generator. The defaults match [`generate_word`].
+    pub fn builder() -> WordGeneratorBuilder<'a> {
+        WordGeneratorBuilder::default()
+    }
+
+    /// Generate a word from `reader`.
+    ///
+    /// Without length bounds this is [`generate_word`]. A minimum alone
+    /// works like [`generate_word_with_target_len`], a maximum alone like
+    /// [`generate_word_with_max_len`], and both like
+    /// [`generate_word_in_range`].
+    ///
+    /// A word on the deny list is replaced by one generated from the bytes
+    /// `reader` has left, up to 8 times. When those run out, or every retry
+    /// is denied too, the last word is returned even if it is denied.
+    pub fn generate<R: ByteReader>(&self, reader: &mut R) -> String {
+        let mut word = self.generate_unfiltered(reader);
+        for _ in 0..DENIED_WORD_RETRIES {
+            if !self.is_denied(&word) {
+                break;
+            }
+            let redrawn = self.generate_unfiltered(reader);
+            if redrawn.is_empty() {
+                break;
+            }
+            word = redrawn;
+        }
+        if self.capitalize {
+            capitalize_first_letter(&mut word);
+        }
+        word
+    }
+
+    /// Generate a word with the length options only.
+    fn generate_unfiltered<R: ByteReader>(&self, reader: &mut R) -> String {
+        match (self.min_len, self.max_len) {
+            (0, None) => generate_word(reader),
+            (min_len, None) => generate_word_with_target_len(reader, min_len),
+            (0, Some(max_len)) => generate_word_with_max_len(reader, max_len),
+            (min_len, Some(max_len)) => generate_word_in_range(reader, min_len, max_len),
+        }
+    }
+
+    /// Whether `word` matches a deny list entry, ignoring ASCII case.
+    fn is_denied(&self, word: &str) -> bool {
+        self.deny_list
+            .iter()
+            .any(|denied| denied.eq_ignore_ascii_case(word))
+    }
+}
+
+/// Builder for a [`WordGenerator`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WordGeneratorBuilder<'a> {
+    generator: WordGenerator<'a>,
+}
+
+impl<'a> WordGeneratorBuilder<'a> {
+    /// Minimum word length in bytes.
+    pub const fn min_len(mut self, min_len: usize) -> Self {
+        self.generator.min_len = min_len;
+        self
+    }
+
+    /// Maximum word length in bytes.
+    pub const fn max_len(mut self, max_len: usize) -> Self {
+        self.generator.max_len = Some(max_len);
+        self
+    }
+
+    /// Uppercase the first ASCII letter of each word.
+    pub const fn capitalize(mut self, capitalize: bool) -> Self {
+        self.generator.capitalize = capitalize;
+        self
+    }
+
+    /// Words to generate again, compared ignoring ASCII case.
+    ///
+    /// A retry reads the entropy after the denied word, so the output
+    /// depends on the deny list: adding an entry changes the words that
+    /// matched it and nothing else. Without a maximum length a word usually
+    /// reads `reader` to the end, leaving nothing for a retry, so set
+    /// [`max_len`](Self::max_len) when filtering matters.
+    pub const fn deny_list(mut self, deny_list: &'a [&'a str]) -> Self {
+        self.generator.deny_list = deny_list;
+        self
+    }
+
+    /// Finish configuring the generator.
+    pub const fn build(self) -> WordGenerator<'a> {
+        self.generator
+    }
+}
+
+/// Uppercase the first ASCII letter of `word`, skipping anything before it.
+pub(crate) fn capitalize_first_letter(word: &mut str) {
+    if let Some(index) = word.find(|character: char| character.is_ascii_alphabetic()) {
+        word[index..=index].make_ascii_uppercase();
+    }
+}
+
+/// Why a generated word ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndReason {
+    /// The end token was selected with entropy from the reader.
+    Natural,
+    /// The reader ran out of bits, so the end token fell back to the first
+    /// choice, or the word was cut short without one.
+    Exhausted,
+}
+
+/// Generate a word like [`generate_word`] and report why it ended.
+///
+/// [`generate_word`] keeps adding middle tokens while at least 8 bits
+/// remain, so a word usually ends [`EndReason::Exhausted`]. It ends
+/// [`EndReason::Natural`] only when the model has no middle token to
+/// continue with and bits are left to choose the end token. An empty reader
+/// yields an empty word and [`EndReason::Exhausted`].
+pub fn generate_word_status<R: ByteReader>(reader: &mut R) -> (String, EndReason) {
+    let (tokens, end_reason, _) = generate_word_until(reader, EndPolicy::Entropy, |_, _| true);
+    (join_tokens(&tokens), end_reason)
+}
+
+/// Generate a word like [`generate_word`] and report how many bytes it
+/// read from `reader`.
+///
+/// Each token reads exactly one byte, so the count tells where the next
+/// word of a long entropy source starts. Only a word that ends naturally
+/// leaves bytes unread; an empty reader yields an empty word and `0`.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xAE, 0xF4, 0xFF, 0xDE, 0xAD];
+/// let mut reader = SliceReader::new(&entropy);
+/// let (word, bytes_read) = english_word::generate_word_counted(&mut reader);
+/// assert_eq!((word.as_str(), bytes_read), ("elatior", 3));
+/// ```
+pub fn generate_word_counted<R: ByteReader>(reader: &mut R) -> (String, usize) {
+    let (tokens, _, bytes_read) = generate_word_until(reader, EndPolicy::Entropy, |_, _| true);
+    (join_tokens(&tokens), bytes_read)
+}
+
+/// Why a word could not be generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordGenError {
+    /// The reader had no byte for the beginning token.
+    EmptyReader,
+    /// The model has neither a middle nor an end token after the last
+    /// token, so the word has no end token.
+    MissingContext,
+    /// The reader ran out, or no token fits, before the word reached the
+    /// target length.
+    UnreachableTargetLen,
+}
+
+impl core::fmt::Display for WordGenError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str(match self {
+            Self::EmptyReader => "the reader has no entropy",
+            Self::MissingContext => "the model cannot end the word",
+            Self::UnreachableTargetLen => "the word cannot reach the target length",
+        })
+    }
+}
+
+impl core::error::Error for WordGenError {}
+
+/// Generate a word like [`generate_word`], failing instead of returning an
+/// empty or unfinished word.
+///
+/// [`generate_word`] returns an empty string for an empty reader and the
+/// partial word when the model cannot end it; here these are
+/// [`WordGenError::EmptyReader`] and [`WordGenError::MissingContext`].
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+/// use english_word::WordGenError;
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF];
+/// assert_eq!(
+///     english_word::try_generate_word(&mut SliceReader::new(&entropy)),
+///     Ok(english_word::generate_word(&mut SliceReader::new(&entropy)))
+/// );
+/// assert_eq!(
+///     english_word::try_generate_word(&mut SliceReader::new(&[])),
+///     Err(WordGenError::EmptyReader)
+/// );
+/// ```
+pub fn try_generate_word<R: ByteReader>(reader: &mut R) -> Result<String, WordGenError> {
+    let (tokens, _, _) = generate_word_until(reader, EndPolicy::Entropy, |_, _| true);
+    match tokens.last() {
+        None => Err(WordGenError::EmptyReader),
+        Some(token_id) if !END_TOKENS.contains(token_id) => Err(WordGenError::MissingContext),
+        Some(_) => Ok(join_tokens(&tokens)),
+    }
+}
+
+/// Generate a word like [`generate_word_with_target_len`], failing instead
+/// of returning an empty or too short word.
+///
+/// [`generate_word_with_target_len`] returns whatever it generated when the
+/// reader runs out first; here a word shorter than `target_len` is
+/// [`WordGenError::UnreachableTargetLen`].
+pub fn try_generate_word_with_target_len<R: ByteReader>(
+    reader: &mut R,
+    target_len: usize,
+) -> Result<String, WordGenError> {
+    let tokens = target_len_tokens(reader, target_len, usize::MAX, text_len);
+    let Some((last_token, _)) = tokens.last() else {
+        return Err(WordGenError::EmptyReader);
+    };
+    let word: String = tokens
+        .iter()
+        .map(|(token_id, _)| token_text(*token_id))
+        .collect();
+    if word.len() < target_len {
+        return Err(WordGenError::UnreachableTargetLen);
+    }
+    if !END_TOKENS.contains(last_token) {
+        return Err(WordGenError::MissingContext);
+    }
+    Ok(word)
+}
+
+/// Generate a word, letting `should_continue` stop it early.
+///
+/// After the beginning token and after each middle token, the closure is
+/// called with the partial word. When it returns `false`, no more middle
+/// tokens are added and the word is finished with an end token chosen from
+/// the next entropy bits. Otherwise generation proceeds as in
+/// [`generate_word`].
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE];
+/// let word = english_word::generate_word_cb(&mut SliceReader::new(&entropy), |partial| {
+///     partial.len() < 4
+/// });
+/// assert!(word.len() < english_word::generate_word(&mut SliceReader::new(&entropy)).len());
+/// ```
+pub fn generate_word_cb<R, F>(reader: &mut R, should_continue: F) -> String
+where
+    R: ByteReader,
+    F: FnMut(&str) -> bool,
+{
+    let mut should_continue = should_continue;
+    let (tokens, _, _) = generate_word_until(reader, EndPolicy::Entropy, |partial, _| {
+        should_continue(partial)
+    });
+    join_tokens(&tokens)
+}
+
+/// The word built by always taking the lexicographically smallest token.
+///
+/// Starts with the smallest beginning token, then repeatedly takes the
+/// smallest of the middle and end tokens that can follow, preferring an end
+/// token on a tie, until an end token is taken. If the walk would revisit a
+/// middle token, it ends with the smallest end token instead, so the
+/// traversal is bounded by the number of tokens. Only tokens that some
+/// entropy byte can select are considered. The result depends only on the
+/// model, so it suits placeholders and sentinels.
+///
+/// # Examples
+/// ```
+/// use readable_hash::english_word;
+///
+/// assert!(!english_word::canonical_min_word().is_empty());
+/// ```
+pub fn canonical_min_word() -> String {
+    let Some(mut current_token) =
+        reachable_tokens(&BEGIN_TRANSITIONS).min_by_key(|token_id| token_text(*token_id))
+    else {
+        return String::new();
+    };
+    let mut result = String::from(token_text(current_token));
+    let mut visited = vec![current_token];
+
+    loop {
+        let smallest_end = reachable_tokens(end_transitions(current_token))
+            .min_by_key(|token_id| token_text(*token_id));
+        let smallest_middle = reachable_tokens(middle_transitions(current_token))
+            .min_by_key(|token_id| token_text(*token_id));
+        let next_middle = match (smallest_middle, smallest_end) {
+            (Some(middle), Some(end)) if token_text(middle) < token_text(end) => middle,
+            (Some(middle), None) => middle,
+            (_, end) => {
+                if let Some(end) = end {
+                    result.push_str(token_text(end));
+                }
+                return result;
+            }
+        };
+        if visited.contains(&next_middle) {
+            if let Some(end) = smallest_end {
+                result.push_str(token_text(end));
+            }
+            return result;
+        }
+        visited.push(next_middle);
+        result.push_str(token_text(next_middle));
+        current_token = next_middle;
+    }
+}
+
+/// Generate a word like [`generate_word`] along with the ids of its tokens.
+///
+/// The ids can be passed to [`token_text`] and [`mutate_word`].
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF];
+/// let (word, tokens) = english_word::generate_word_tokens(&mut SliceReader::new(&entropy));
+/// let texts: Vec<&str> = tokens.iter().map(|token_id| english_word::token_text(*token_id)).collect();
+/// assert_eq!(texts.concat(), word);
+/// ```
+pub fn generate_word_tokens<R: ByteReader>(reader: &mut R) -> (String, Vec<u16>) {
+    let (tokens, _, _) = generate_word_until(reader, EndPolicy::Entropy, |_, _| true);
+    (join_tokens(&tokens), tokens)
+}
+
+/// Change one token of a generated word to another token the model allows
+/// in its place, for A/B variations of a name.
+///
+/// `tokens` are the ids the word was generated from, as returned by
+/// [`generate_word_tokens`]. `seed` picks the token to change; the
+/// replacement is the next most probable token that can follow the
+/// previous one (or can begin a word), skipping tokens with the same text
+/// and wrapping around to the most probable. Later tokens are kept, even if
+/// the model would not have chosen them after the replacement. If the
+/// chosen token has no alternative, the following ones are tried in turn.
+/// The word is returned unchanged when no token can change or when `tokens`
+/// do not spell `word`.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF];
+/// let (word, tokens) = english_word::generate_word_tokens(&mut SliceReader::new(&entropy));
+/// let mutated = english_word::mutate_word(&word, &tokens, 1);
+/// assert_ne!(mutated, word);
+/// ```
+pub fn mutate_word(word: &str, tokens: &[u16], seed: u8) -> String {
+    if tokens.is_empty()
+        || tokens
+            .iter()
+            .any(|token_id| usize::from(*token_id) >= TOKENS.len())
+        || join_tokens(tokens) != word
+    {
+        return word.to_string();
+    }
+
+    let start = usize::from(seed) % tokens.len();
+    for offset in 0..tokens.len() {
+        let position = (start + offset) % tokens.len();
+        let current = tokens[position];
+        let transitions: &[(u16, u8)] = match position.checked_sub(1) {
+            None => &BEGIN_TRANSITIONS,
+            Some(previous) if END_TOKENS.contains(&current) => end_transitions(tokens[previous]),
+            Some(previous) => middle_transitions(tokens[previous]),
+        };
+        let alternatives: Vec<u16> = reachable_tokens(transitions).collect();
+        let current_index = alternatives
+            .iter()
+            .position(|token_id| *token_id == current)
+            .unwrap_or(alternatives.len());
+        let replacement = (1..=alternatives.len())
+            .map(|step| alternatives[(current_index + step) % alternatives.len()])
+            .find(|token_id| token_text(*token_id) != token_text(current));
+        if let Some(replacement) = replacement {
+            let mut mutated = join_tokens(&tokens[..position]);
+            mutated.push_str(token_text(replacement));
+            mutated.push_str(&join_tokens(&tokens[position + 1..]));
+            return mutated;
+        }
+    }
+
+    word.to_string()
+}
+
+/// Generate a word like [`generate_word`], passing each token's text
+/// through `map_token` before it is joined.
+///
+/// Useful for styling that works per token, such as uppercasing vowels or
+/// adding diacritics. An identity map gives the same word as
+/// [`generate_word`].
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF];
+/// let word = english_word::generate_word_mapped(&mut SliceReader::new(&entropy), |token| {
+///     format!("[{token}]")
+/// });
+/// assert_eq!(word, "[sy][ru][le][ol][e]");
+/// ```
+pub fn generate_word_mapped<R, F>(reader: &mut R, map_token: F) -> String
+where
+    R: ByteReader,
+    F: Fn(&str) -> String,
+{
+    generate_word_until(reader, EndPolicy::Entropy, |_, _| true)
+        .0
+        .into_iter()
+        .map(|token_id| map_token(token_text(token_id)))
+        .collect()
+}
+
+/// Options for [`generate_word_opts`].
+///
+/// The default options generate the same word as [`generate_word`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenOpts {
+    /// Length after which the word becomes increasingly likely to end.
+    ///
+    /// Once the word reaches `soft_max_len`, each step spends one entropy
+    /// byte deciding whether to end. The chance to continue starts at 1/2
+    /// and halves for every character over the limit, so long words stay
+    /// possible but rare. `None` keeps going while entropy lasts.
+    pub soft_max_len: Option<usize>,
+    /// How the end token is chosen.
+    pub end_policy: EndPolicy,
+    /// What to do with a word shorter than a minimum length.
+    pub on_short: OnShort,
+}
+
+/// How [`generate_word_opts`] chooses the end token.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EndPolicy {
+    /// Select the end token with the next entropy byte, as
+    /// [`generate_word`] does. When the reader is exhausted, the end token
+    /// is selected as if that byte were `0`, which picks the first entry of
+    /// the model's table.
+    #[default]
+    Entropy,
+    /// Always use the most likely end token, without reading entropy, so
+    /// the word is the same whether or not a byte was left for the end
+    /// token.
+    MostLikely,
+}
+
+/// How [`generate_word_opts`] treats a word that comes out too short.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OnShort {
+    /// Return the word as generated.
+    #[default]
+    Keep,
+    /// Extend a word shorter than `min_len` characters.
+    ///
+    /// The end token is replaced by filler middle tokens, each the most
+    /// likely one to follow the previous token, and the word is closed
+    /// with the most likely end token. No entropy is read for padding, so
+    /// padded words are deterministic but less varied; use
+    /// [`generate_word_opts_padded`] to tell them apart. A word the model
+    /// cannot extend is returned unchanged.
+    Pad {
+        /// Minimum word length in bytes.
+        min_len: usize,
+    },
+}
+
+/// Generate a word shaped by `opts`.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+/// use english_word::GenOpts;
+///
+/// let entropy = [0x5A; 64];
+/// let opts = GenOpts { soft_max_len: Some(8), ..GenOpts::default() };
+/// let word = english_word::generate_word_opts(&mut SliceReader::new(&entropy), &opts);
+/// assert!(word.len() < english_word::generate_word(&mut SliceReader::new(&entropy)).len());
+/// ```
+pub fn generate_word_opts<R: ByteReader>(reader: &mut R, opts: &GenOpts) -> String {
+    generate_word_opts_padded(reader, opts).0
+}
+
+/// Generate a word shaped by `opts` and report whether it was padded.
+///
+/// The flag is `true` only when [`OnShort::Pad`] added filler tokens.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+/// use english_word::{GenOpts, OnShort};
+///
+/// let opts = GenOpts { on_short: OnShort::Pad { min_len: 10 }, ..GenOpts::default() };
+/// let (word, padded) = english_word::generate_word_opts_padded(&mut SliceReader::new(&[0x5A]), &opts);
+/// assert!(padded);
+/// assert!(word.len() >= 10);
+/// ```
+pub fn generate_word_opts_padded<R: ByteReader>(reader: &mut R, opts: &GenOpts) -> (String, bool) {
+    let (tokens, _, _) = generate_word_until(reader, opts.end_policy, |partial, bit_reader| {
+        let Some(soft_max_len) = opts.soft_max_len else {
+            return true;
+        };
+        let Some(over) = partial.len().checked_sub(soft_max_len) else {
+            return true;
+        };
+        // Continue with probability 2^-(over + 1)
+        let continue_below = 256_u16.checked_shr(over as u32 + 1).unwrap_or(0);
+        bit_reader
+            .read_u8()
+            .is_some_and(|value| u16::from(value) < continue_below)
+    });
+    let OnShort::Pad { min_len } = opts.on_short else {
+        return (join_tokens(&tokens), false);
+    };
+    match pad_tokens(&tokens, min_len) {
+        Some(padded) => (join_tokens(&padded), true),
+        None => (join_tokens(&tokens), false),
+    }
+}
+
+/// Extend `tokens` with the most likely middle tokens until the word,
+/// closed by the most likely end token, is at least `min_len` long.
+///
+/// Returns `None` when the word is already long enough or the model offers
+/// no longer word.
+fn pad_tokens(tokens: &[u16], min_len: usize) -> Option<Vec<u16>> {
+    let original_len = tokens.iter().map(|token_id| text_len(*token_id)).sum();
+    if original_len >= min_len {
+        return None;
+    }
+    let mut padded = tokens.to_vec();
+    if padded
+        .last()
+        .is_some_and(|token_id| END_TOKENS.contains(token_id))
+    {
+        padded.pop();
+    }
+    let mut current = *padded.last()?;
+    let mut len: usize = padded.iter().map(|token_id| text_len(*token_id)).sum();
+    let closing_len = |token_id: u16| {
+        let trans = end_transitions(token_id);
+        if trans.is_empty() {
+            0
+        } else {
+            text_len(most_likely_token(trans))
+        }
+    };
+    while len + closing_len(current) < min_len {
+        let trans = middle_transitions(current);
+        if trans.is_empty() {
+            break;
+        }
+        let filler = most_likely_token(trans);
+        if text_len(filler) == 0 {
+            break;
+        }
+        len += text_len(filler);
+        padded.push(filler);
+        current = filler;
+    }
+    let trans = end_transitions(current);
+    if !trans.is_empty() {
+        padded.push(most_likely_token(trans));
+    }
+    let padded_len: usize = padded.iter().map(|token_id| text_len(*token_id)).sum();
+    (padded_len > original_len).then_some(padded)
+}
+
+/// Shared implementation of [`generate_word_status`], [`generate_word_cb`]
+/// and [`generate_word_opts`].
+///
+/// Returns the ids of the chosen tokens, why the word ended and how many
+/// bytes were read from `reader`. `should_continue` may draw from the bit
+/// reader to decide; it is only called while at least 8 bits remain.
+fn generate_word_until<R, F>(
+    reader: &mut R,
+    end_policy: EndPolicy,
+    mut should_continue: F,
+) -> (Vec<u16>, EndReason, usize)
+where
+    R: ByteReader,
+    F: FnMut(&str, &mut BitReader<'_, R>) -> bool,
+{
     let mut bit_reader = BitReader::new(reader);
     let mut result = String::new();
+    let mut tokens = Vec::new();
 
     // Select beginning token
     let Some(begin_value) = bit_reader.read_u8() else {
-        return String::new();
+        return (tokens, EndReason::Exhausted, bit_reader.bytes_read());
     };
     let first_token = find_token(&BEGIN_TRANSITIONS, begin_value);
     let mut current_token: Option<u16> = Some(first_token);
     result.push_str(token_text(first_token));
+    tokens.push(first_token);
 
     // Select middle tokens while we have entropy
-    while bit_reader.has_more() {
+    while bit_reader.has_more_bits(8) && should_continue(&result, &mut bit_reader) {
         let Some(current) = current_token else {
             break;
         };
-        let (start, len) = TRANSITION_INDEX[current as usize];
-        if len == 0 {
+        let trans = middle_transitions(current);
+        if trans.is_empty() {
             break;
         }
         let Some(value) = bit_reader.read_u8() else {
             break;
         };
-        let trans = &TRANSITION_DATA[start as usize..(start as usize + len as usize)];
         let next_token = find_token(trans, value);
         current_token = Some(next_token);
         result.push_str(token_text(next_token));
+        tokens.push(next_token);
     }
 
     // Select end token using remaining bits or default
+    let mut end_reason = EndReason::Exhausted;
     if let Some(current) = current_token {
-        let (start, len) = END_TRANSITION_INDEX[current as usize];
-        if len > 0 {
-            let trans = &END_TRANSITION_DATA[start as usize..(start as usize + len as usize)];
-            let value = bit_reader.read_u8().unwrap_or(0);
-            let end_token = find_token(trans, value);
-            result.push_str(token_text(end_token));
+        let trans = end_transitions(current);
+        if !trans.is_empty() {
+            let end_token = match end_policy {
+                EndPolicy::Entropy => {
+                    let value = match bit_reader.read_u8() {
+                        Some(value) => {
+                            end_reason = EndReason::Natural;
+                            value
+                        }
+                        None => 0,
+                    };
+                    find_token(trans, value)
+                }
+                EndPolicy::MostLikely => most_likely_token(trans),
+            };
+            tokens.push(end_token);
+        }
+    }
+
+    (tokens, end_reason, bit_reader.bytes_read())
+}
+
+/// Generate a word and insert `infix` at a token boundary chosen by the
+/// entropy.
+///
+/// The first byte of the reader selects the boundary; the word is generated
+/// from the rest like [`generate_word`]. The infix goes between two tokens,
+/// never at the very start or end of the word and never inside a token. A
+/// word of a single token has no such boundary, so the infix is appended.
+/// An empty reader yields an empty string.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0x01, 0xDE, 0xAD, 0xBE, 0xEF];
+/// let word = english_word::generate_word_infix(&mut SliceReader::new(&entropy), "-co-");
+/// assert_eq!(word.matches("-co-").count(), 1);
+/// assert_eq!(
+///     word.replace("-co-", ""),
+///     english_word::generate_word(&mut SliceReader::new(&entropy[1..]))
+/// );
+/// ```
+pub fn generate_word_infix<R: ByteReader>(reader: &mut R, infix: &str) -> String {
+    let mut selector = [0u8; 1];
+    if reader.read(&mut selector) == 0 {
+        return String::new();
+    }
+    let (tokens, _, _) = generate_word_until(reader, EndPolicy::Entropy, |_, _| true);
+    if tokens.is_empty() {
+        return String::new();
+    }
+
+    // Boundaries between tokens, excluding the start and end of the word
+    let boundary_count = tokens.len() - 1;
+    let split_at = if boundary_count == 0 {
+        tokens.len()
+    } else {
+        1 + usize::from(selector[0]) % boundary_count
+    };
+
+    let mut result = join_tokens(&tokens[..split_at]);
+    result.push_str(infix);
+    result.push_str(&join_tokens(&tokens[split_at..]));
+    result
+}
+
+/// Generate a word of `min_tokens..=max_tokens` tokens.
+///
+/// The token count includes the beginning and end tokens, so windows are
+/// clamped to at least two tokens, and `min_tokens` to at most `max_tokens`.
+/// Middle tokens are added while entropy lasts, until only the end token
+/// fits in the window, so plentiful entropy always produces `max_tokens`
+/// tokens. If the reader runs dry before `min_tokens`, the remaining tokens
+/// are chosen as if the missing bits were zero. The window cannot be met
+/// when the model has no continuation for a token.
+pub fn generate_word_token_window<R: ByteReader>(
+    reader: &mut R,
+    min_tokens: usize,
+    max_tokens: usize,
+) -> String {
+    let max_tokens = max_tokens.max(2);
+    let min_tokens = min_tokens.min(max_tokens);
+    let mut bit_reader = BitReader::new(reader);
+
+    let Some(begin_value) = bit_reader.read_u8() else {
+        return String::new();
+    };
+    let mut current_token = find_token(&BEGIN_TRANSITIONS, begin_value);
+    let mut result = String::from(token_text(current_token));
+    let mut token_count = 1;
+
+    // Leave room for the end token
+    while token_count + 1 < max_tokens {
+        let trans = middle_transitions(current_token);
+        if trans.is_empty() {
+            break;
         }
+        let value = match bit_reader.read_u8() {
+            Some(value) => value,
+            None if token_count + 1 < min_tokens => 0,
+            None => break,
+        };
+        current_token = find_token(trans, value);
+        result.push_str(token_text(current_token));
+        token_count += 1;
+    }
+
+    let trans = end_transitions(current_token);
+    if !trans.is_empty() {
+        let value = bit_reader.read_u8().unwrap_or(0);
+        result.push_str(token_text(find_token(trans, value)));
     }
 
     result
 }
+
+/// Generate a word and measure how long generation took.
+///
+/// The word is identical to [`generate_word`]. The duration is wall-clock
+/// time, so it includes time spent in the reader.
+#[cfg(feature = "timing")]
+pub fn generate_word_timed<R: ByteReader>(reader: &mut R) -> (String, std::time::Duration) {
+    let started = std::time::Instant::now();
+    let word = generate_word(reader);
+    (word, started.elapsed())
+}
+
+/// Number of entropy bytes used for each word of a multi-word result.
+const WORD_ENTROPY_BYTES: usize = 4;
+
+/// Generate a word from the next [`WORD_ENTROPY_BYTES`] of `reader`.
+///
+/// Returns the word and whether a full slice was available, or `None` if
+/// the reader is exhausted.
+fn generate_slice_word<R: ByteReader>(reader: &mut R) -> Option<(String, bool)> {
+    let mut slice = [0u8; WORD_ENTROPY_BYTES];
+    let filled = fill_slice(reader, &mut slice);
+    if filled == 0 {
+        return None;
+    }
+    let word = generate_word(&mut SliceReader::new(&slice[..filled]));
+    Some((word, filled == slice.len()))
+}
+
+/// Read from `reader` until `slice` is full or the reader is exhausted.
+///
+/// Returns the number of bytes read.
+fn fill_slice<R: ByteReader>(reader: &mut R, slice: &mut [u8]) -> usize {
+    let mut filled = 0;
+    while filled < slice.len() {
+        let bytes_read = reader.read(&mut slice[filled..]);
+        if bytes_read == 0 {
+            break;
+        }
+        filled += bytes_read;
+    }
+    filled
+}
+
+/// Generate one word per entry of `lengths`, each at least that long.
+///
+/// Each word is generated with [`generate_word_with_target_len`] from its
+/// own slice of `max(length, 4)` bytes, so every word starts at a fixed
+/// offset in the reader. Stops early when the reader is exhausted; a word
+/// whose slice was cut short may miss its target length.
+pub fn generate_word_series_lengths<R: ByteReader>(
+    reader: &mut R,
+    lengths: &[usize],
+) -> Vec<String> {
+    let mut words = Vec::with_capacity(lengths.len());
+    for length in lengths {
+        let mut slice = vec![0u8; (*length).max(WORD_ENTROPY_BYTES)];
+        let filled = fill_slice(reader, &mut slice);
+        if filled == 0 {
+            break;
+        }
+        let mut slice_reader = SliceReader::new(&slice[..filled]);
+        words.push(generate_word_with_target_len(&mut slice_reader, *length));
+    }
+    words
+}
+
+/// Generate up to `count` distinct words from successive entropy slices.
+///
+/// Each candidate is generated from the next 4 bytes of the reader, and
+/// candidates equal to an earlier one are skipped. Fewer than `count` words
+/// are returned only when the reader is exhausted.
+pub fn generate_candidates<R: ByteReader>(reader: &mut R, count: usize) -> Vec<String> {
+    let mut candidates: Vec<String> = Vec::with_capacity(count);
+
+    while candidates.len() < count {
+        let Some((word, full_slice)) = generate_slice_word(reader) else {
+            break;
+        };
+        if !word.is_empty() && !candidates.contains(&word) {
+            candidates.push(word);
+        }
+        if !full_slice {
+            break;
+        }
+    }
+
+    candidates
+}
+
+/// Generate words from successive entropy slices until the reader is
+/// exhausted.
+///
+/// Each word is generated from the next 4 bytes of the reader, as in
+/// [`generate_phrase`], and a shorter last slice still yields a word. The
+/// iterator ends once no byte is left. Readers that never run out, such as
+/// XOF readers, give an endless iterator, so bound it with
+/// [`Iterator::take`].
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE];
+/// let words: Vec<String> = english_word::generate_words(&mut SliceReader::new(&entropy)).collect();
+/// assert_eq!(words.len(), 2);
+/// assert_eq!(
+///     words.join("-"),
+///     english_word::generate_passphrase(&mut SliceReader::new(&entropy), 2, "-")
+/// );
+/// ```
+pub fn generate_words<R: ByteReader>(reader: &mut R) -> impl Iterator<Item = String> + '_ {
+    core::iter::from_fn(move || generate_slice_word(reader).map(|(word, _)| word))
+}
+
+/// Number of words [`generate_word_matching`] tries before settling for the
+/// closest candidate.
+#[cfg(feature = "regex")]
+const MATCH_ATTEMPTS: usize = 64;
+
+/// Generate a word that matches `pattern`.
+///
+/// Words are generated from successive 4-byte slices of the reader until
+/// one matches, for at most 64 attempts. If none matches, the candidate
+/// with the longest prefix matching `pattern` is returned, or the first
+/// candidate when no prefix matched at all.
+#[cfg(feature = "regex")]
+pub fn generate_word_matching<R: ByteReader>(reader: &mut R, pattern: &regex::Regex) -> String {
+    let mut best_word: Option<String> = None;
+    let mut best_match_len = 0;
+
+    for _ in 0..MATCH_ATTEMPTS {
+        let Some((word, full_slice)) = generate_slice_word(reader) else {
+            break;
+        };
+        if pattern.is_match(&word) {
+            return word;
+        }
+        let match_len = (1..word.len())
+            .rev()
+            .find(|len| word.is_char_boundary(*len) && pattern.is_match(&word[..*len]))
+            .unwrap_or(0);
+        if best_word.is_none() || match_len > best_match_len {
+            best_match_len = match_len;
+            best_word = Some(word);
+        }
+        if !full_slice {
+            break;
+        }
+    }
+
+    best_word.unwrap_or_default()
+}
+
+/// How words of a phrase are joined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeparatorPolicy<'a> {
+    /// Join words with a fixed string.
+    Fixed(&'a str),
+    /// Join words with a character picked from the set by one entropy byte.
+    ///
+    /// The byte is read before any word, so the same entropy always yields
+    /// the same separator. An empty set joins words without a separator.
+    FromDigest(&'a [char]),
+}
+
+/// Generate a phrase of up to `word_count` words.
+///
+/// Each word is generated from the next 4 bytes of the reader. If the
+/// reader runs out mid-phrase, the words generated so far are returned.
+pub fn generate_phrase<R: ByteReader>(
+    reader: &mut R,
+    word_count: usize,
+    separator: SeparatorPolicy<'_>,
+) -> String {
+    let mut separator_buffer = [0u8; 4];
+    let separator = resolve_separator(reader, separator, &mut separator_buffer);
+
+    let mut phrase = String::new();
+    for i in 0..word_count {
+        let Some((word, _)) = generate_slice_word(reader) else {
+            break;
+        };
+        if i > 0 {
+            phrase.push_str(separator);
+        }
+        phrase.push_str(&word);
+    }
+    phrase
+}
+
+/// Generate a passphrase of up to `word_count` words joined by `separator`.
+///
+/// Shorthand for [`generate_phrase`] with [`SeparatorPolicy::Fixed`]. Words
+/// consume the reader in order, so the same entropy always gives the same
+/// passphrase, and a reader that runs dry yields fewer words.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE, 0xBA, 0xBE];
+/// let passphrase = english_word::generate_passphrase(&mut SliceReader::new(&entropy), 3, "-");
+/// assert_eq!(passphrase.split('-').count(), 2);
+/// ```
+pub fn generate_passphrase<R: ByteReader>(
+    reader: &mut R,
+    word_count: usize,
+    separator: &str,
+) -> String {
+    generate_phrase(reader, word_count, SeparatorPolicy::Fixed(separator))
+}
+
+/// Generate a pronounceable password of at least `min_chars` characters
+/// and `min_words` words.
+///
+/// Words come from successive 4-byte entropy slices as in
+/// [`generate_phrase`], and a digit taken from the byte before each word
+/// after the first separates them. Words are added until both minimums
+/// are met. A reader that runs dry first ends the password early, so check
+/// its length when the reader is finite.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, RepeatReader};
+///
+/// let password = english_word::generate_password(&mut RepeatReader::new(&[0xDE, 0xAD, 0xBE, 0xEF, 0x07]), 20, 2);
+/// assert!(password.len() >= 20);
+/// assert!(password.contains(|character: char| character.is_ascii_digit()));
+/// ```
+pub fn generate_password<R: ByteReader>(
+    reader: &mut R,
+    min_chars: usize,
+    min_words: usize,
+) -> String {
+    let mut password = String::new();
+    let mut word_count = 0;
+    while password.len() < min_chars || word_count < min_words {
+        let mut digit = [0u8; 1];
+        if word_count > 0 && reader.read(&mut digit) == 0 {
+            break;
+        }
+        let Some((word, _)) = generate_slice_word(reader) else {
+            break;
+        };
+        if word_count > 0 {
+            password.push(char::from(b'0' + digit[0] % 10));
+        }
+        password.push_str(&word);
+        word_count += 1;
+    }
+    password
+}
+
+/// Entropy bytes [`generate_word_from_seed`] expands a seed into.
+const SEED_WORD_BYTES: usize = 8;
+
+/// Generate a word from `seed` instead of a digest.
+///
+/// The seed is expanded into 8 bytes with `SplitMix64`, so the same seed
+/// always yields the same word.
+///
+/// # Examples
+/// ```
+/// use readable_hash::english_word;
+///
+/// assert_eq!(english_word::generate_word_from_seed(7), english_word::generate_word_from_seed(7));
+/// ```
+pub fn generate_word_from_seed(seed: u64) -> String {
+    let entropy = seed_bytes(seed, SEED_WORD_BYTES);
+    generate_word(&mut SliceReader::new(&entropy))
+}
+
+/// Generate a passphrase of `word_count` words joined by `-` from `seed`.
+///
+/// The seed is expanded with `SplitMix64` into 4 bytes per word, which
+/// [`generate_passphrase`] turns into words.
+///
+/// # Examples
+/// ```
+/// use readable_hash::english_word;
+///
+/// let passphrase = english_word::generate_passphrase_from_seed(7, 4);
+/// assert_eq!(passphrase.split('-').count(), 4);
+/// ```
+pub fn generate_passphrase_from_seed(seed: u64, word_count: usize) -> String {
+    let entropy = seed_bytes(seed, word_count * 4);
+    generate_passphrase(&mut SliceReader::new(&entropy), word_count, "-")
+}
+
+/// The first `len` bytes of the `SplitMix64` stream of `seed`.
+fn seed_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut stream = SplitMix64::new(seed);
+    (0..len).map(|_| stream.next_byte()).collect()
+}
+
+/// Write a phrase of up to `word_count` words to `out`.
+///
+/// Produces the same output as [`generate_phrase`], but each word and
+/// separator is written as soon as it is generated instead of being
+/// collected first.
+///
+/// # Errors
+///
+/// Returns any error produced while writing to `out`.
+#[cfg(feature = "std")]
+pub fn write_phrase<R: ByteReader, W: Write>(
+    reader: &mut R,
+    word_count: usize,
+    separator: SeparatorPolicy<'_>,
+    mut out: W,
+) -> io::Result<()> {
+    let mut separator_buffer = [0u8; 4];
+    let separator = resolve_separator(reader, separator, &mut separator_buffer);
+
+    for i in 0..word_count {
+        let Some((word, _)) = generate_slice_word(reader) else {
+            break;
+        };
+        if i > 0 {
+            out.write_all(separator.as_bytes())?;
+        }
+        out.write_all(word.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Resolve a separator policy to the string placed between words.
+fn resolve_separator<'a, R: ByteReader>(
+    reader: &mut R,
+    separator: SeparatorPolicy<'a>,
+    buffer: &'a mut [u8; 4],
+) -> &'a str {
+    match separator {
+        SeparatorPolicy::Fixed(text) => text,
+        SeparatorPolicy::FromDigest(characters) => {
+            let mut byte = [0u8; 1];
+            if characters.is_empty() || reader.read(&mut byte) == 0 {
+                ""
+            } else {
+                let character = characters[byte[0] as usize % characters.len()];
+                character.encode_utf8(buffer)
+            }
+        }
+    }
+}
+
+/// Similarity of two words in `0.0..=1.0`, where `1.0` means identical.
+///
+/// Computed as one minus the Levenshtein distance over characters divided
+/// by the length of the longer word. Two empty words are identical.
+///
+/// # Examples
+/// ```
+/// use readable_hash::english_word::readable_similarity;
+///
+/// assert_eq!(readable_similarity("there", "there"), 1.0);
+/// assert_eq!(readable_similarity("there", "where"), 0.8);
+/// assert_eq!(readable_similarity("abc", "xyz"), 0.0);
+/// ```
+pub fn readable_similarity(left: &str, right: &str) -> f64 {
+    let left: Vec<char> = left.chars().collect();
+    let right: Vec<char> = right.chars().collect();
+    let longest = left.len().max(right.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    // Single-row Levenshtein distance
+    let mut row: Vec<usize> = (0..=right.len()).collect();
+    for (i, left_char) in left.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = diagonal + usize::from(left_char != right_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    1.0 - row[right.len()] as f64 / longest as f64
+}
+
+/// Number of words [`generate_dissimilar`] tries before settling for the
+/// most dissimilar candidate.
+const DISSIMILAR_ATTEMPTS: usize = 64;
+
+/// Generate a word that is not too similar to `avoid`.
+///
+/// Words are generated from successive 4-byte slices of the reader until
+/// one is at least `min_distance` away from `avoid`, where the distance is
+/// `1.0 - readable_similarity(word, avoid)`, for at most 64 attempts. If
+/// none is far enough, the most dissimilar candidate is returned.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE, 0xBA, 0xBE];
+/// let word = english_word::generate_dissimilar(&mut SliceReader::new(&entropy), "syrule", 0.5);
+/// assert!(1.0 - english_word::readable_similarity(&word, "syrule") >= 0.5);
+/// ```
+pub fn generate_dissimilar<R: ByteReader>(
+    reader: &mut R,
+    avoid: &str,
+    min_distance: f64,
+) -> String {
+    let mut best_word = String::new();
+    let mut best_distance = -1.0;
+
+    for _ in 0..DISSIMILAR_ATTEMPTS {
+        let Some((word, full_slice)) = generate_slice_word(reader) else {
+            break;
+        };
+        let distance = 1.0 - readable_similarity(&word, avoid);
+        if distance >= min_distance {
+            return word;
+        }
+        if distance > best_distance {
+            best_distance = distance;
+            best_word = word;
+        }
+        if !full_slice {
+            break;
+        }
+    }
+
+    best_word
+}
+
+/// Number of attempts [`generate_palindrome`] makes before settling for the
+/// most symmetric candidate.
+const PALINDROME_ATTEMPTS: usize = 32;
+
+/// Generate a word that reads the same forwards and backwards when possible.
+///
+/// Each attempt draws a beginning token and, when entropy allows, one middle
+/// token, then tries every end token the model permits after them. The first
+/// palindrome found is returned. If none of the 32 attempts produce one, the
+/// candidate with the highest share of matching mirrored characters is
+/// returned instead.
+/// Each attempt consumes two bytes of entropy.
+pub fn generate_palindrome<R: ByteReader>(reader: &mut R) -> String {
+    let mut bit_reader = BitReader::new(reader);
+    let mut best_word = String::new();
+    let mut best_score = -1.0;
+
+    for _ in 0..PALINDROME_ATTEMPTS {
+        let Some(begin_value) = bit_reader.read_u8() else {
+            break;
+        };
+        let first_token = find_token(&BEGIN_TRANSITIONS, begin_value);
+        let mut paths = vec![vec![first_token]];
+        let trans = middle_transitions(first_token);
+        if !trans.is_empty()
+            && let Some(value) = bit_reader.read_u8()
+        {
+            paths.push(vec![first_token, find_token(trans, value)]);
+        }
+
+        for path in paths {
+            let prefix: String = path.iter().map(|token_id| token_text(*token_id)).collect();
+            let last_token = path[path.len() - 1];
+            for (end_id, _) in end_transitions(last_token) {
+                let word = format!("{prefix}{}", token_text(*end_id));
+                let score = symmetry_score(&word);
+                if score >= 1.0 {
+                    return word;
+                }
+                if score > best_score {
+                    best_score = score;
+                    best_word = word;
+                }
+            }
+        }
+    }
+
+    best_word
+}
+
+/// Fraction of mirrored character pairs that match, in `0.0..=1.0`.
+fn symmetry_score(word: &str) -> f64 {
+    let bytes = word.as_bytes();
+    let pair_count = bytes.len() / 2;
+    if pair_count == 0 {
+        return 1.0;
+    }
+    let matching = (0..pair_count)
+        .filter(|i| bytes[*i] == bytes[bytes.len() - 1 - i])
+        .count();
+    matching as f64 / pair_count as f64
+}
+
+/// Generate a word along with its reversed form, for suffix-sorted indexes.
+///
+/// Token texts are ASCII, so reversing the characters keeps every letter
+/// intact.
+pub fn generate_word_both<R: ByteReader>(reader: &mut R) -> (String, String) {
+    let word = generate_word(reader);
+    let reversed = word.chars().rev().collect();
+    (word, reversed)
+}
+
+/// Generate variant number `variant` of the word for the reader's entropy.
+///
+/// The entropy is XORed with a keystream derived from `variant` before
+/// generating the word like [`generate_word`], so each variant is a
+/// reproducible, usually distinct word from the same entropy. Variant `0`
+/// uses the entropy as is and matches [`generate_word`]. Distinct variants
+/// can still collide, most likely when there are only a few entropy bytes.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF];
+/// let first = english_word::generate_word_variant(&mut SliceReader::new(&entropy), 1);
+/// let second = english_word::generate_word_variant(&mut SliceReader::new(&entropy), 2);
+/// assert_ne!(first, second);
+/// assert_eq!(
+///     english_word::generate_word_variant(&mut SliceReader::new(&entropy), 0),
+///     english_word::generate_word(&mut SliceReader::new(&entropy))
+/// );
+/// ```
+pub fn generate_word_variant<R: ByteReader>(reader: &mut R, variant: u32) -> String {
+    let mut variant_reader = VariantReader {
+        inner: reader,
+        keystream: SplitMix64::new(u64::from(variant)),
+        variant,
+    };
+    generate_word(&mut variant_reader)
+}
+
+/// A `ByteReader` that XORs its inner reader with a variant keystream.
+///
+/// Variant `0` passes bytes through unchanged.
+struct VariantReader<'a, R: ByteReader> {
+    inner: &'a mut R,
+    keystream: SplitMix64,
+    variant: u32,
+}
+
+impl<R: ByteReader> ByteReader for VariantReader<'_, R> {
+    fn read(&mut self, dest: &mut [u8]) -> usize {
+        let bytes_read = self.inner.read(dest);
+        if self.variant != 0 {
+            for byte in &mut dest[..bytes_read] {
+                *byte ^= self.keystream.next_byte();
+            }
+        }
+        bytes_read
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        self.inner.remaining()
+    }
+}
+
+/// `SplitMix64` byte stream for deterministic, seeded choices.
+struct SplitMix64 {
+    state: u64,
+    block: [u8; 8],
+    position: usize,
+}
+
+impl SplitMix64 {
+    const fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            block: [0; 8],
+            position: 8,
+        }
+    }
+
+    fn next_byte(&mut self) -> u8 {
+        if self.position == self.block.len() {
+            self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut mixed = self.state;
+            mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            mixed ^= mixed >> 31;
+            self.block = mixed.to_le_bytes();
+            self.position = 0;
+        }
+        let byte = self.block[self.position];
+        self.position += 1;
+        byte
+    }
+}
+
+/// Leetspeak substitutions. The digits are distinct and never appear in
+/// generated words, so [`unleet`] can undo them.
+const LEET_SUBSTITUTIONS: [(char, char); 6] = [
+    ('a', '4'),
+    ('e', '3'),
+    ('i', '1'),
+    ('o', '0'),
+    ('s', '5'),
+    ('t', '7'),
+];
+
+/// Generate a word with deterministic leetspeak styling, e.g. `"th3r3a7s"`.
+///
+/// The first 8 bytes of the reader seed the substitution choices; the word
+/// is generated from the rest like [`generate_word`]. Each character that
+/// has a substitution (`a e i o s t`) is replaced with probability
+/// `intensity / 256`, except that `0` replaces none and `255` replaces all.
+/// Use [`unleet`] to recover the base word. A reader with fewer than 8
+/// bytes yields an empty string.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, SliceReader};
+///
+/// let entropy = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0xDE, 0xAD, 0xBE, 0xEF];
+/// let leet = english_word::generate_word_leet(&mut SliceReader::new(&entropy), 128);
+/// assert_eq!(
+///     english_word::unleet(&leet),
+///     english_word::generate_word(&mut SliceReader::new(&entropy[8..]))
+/// );
+/// ```
+pub fn generate_word_leet<R: ByteReader>(reader: &mut R, intensity: u8) -> String {
+    let mut seed = [0u8; 8];
+    let seed_len = fill_slice(reader, &mut seed);
+    if seed_len < seed.len() {
+        return String::new();
+    }
+    let mut keystream = SplitMix64::new(u64::from_le_bytes(seed));
+
+    generate_word(reader)
+        .chars()
+        .map(|character| {
+            let Some((_, substitute)) = LEET_SUBSTITUTIONS
+                .iter()
+                .find(|(plain, _)| *plain == character)
+            else {
+                return character;
+            };
+            let roll = keystream.next_byte();
+            if intensity == u8::MAX || roll < intensity {
+                *substitute
+            } else {
+                character
+            }
+        })
+        .collect()
+}
+
+/// Undo the substitutions made by [`generate_word_leet`].
+///
+/// # Examples
+/// ```
+/// use readable_hash::english_word;
+///
+/// assert_eq!(english_word::unleet("7h3r3"), "there");
+/// ```
+pub fn unleet(word: &str) -> String {
+    word.chars()
+        .map(|character| {
+            LEET_SUBSTITUTIONS
+                .iter()
+                .find(|(_, substitute)| *substitute == character)
+                .map_or(character, |(plain, _)| *plain)
+        })
+        .collect()
+}
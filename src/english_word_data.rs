@@ -3,6 +3,12 @@
 //! This module is auto-generated by `models/generate_rust.py`
 //! Do not edit manually.
 
+/// Token ids of beginning tokens (`^prefix`).
+pub const BEGIN_TOKENS: core::ops::Range<u16> = 0..256;
+
+/// Token ids of end tokens (`suffix$`).
+pub const END_TOKENS: core::ops::Range<u16> = 256..512;
+
 /// Token vocabulary - maps token ID to token string.
 /// Tokens 0-255 are beginning tokens (^prefix).
 /// Tokens 256-511 are end tokens (suffix$).
@@ -87,6 +93,42 @@ pub const TOKENS: [&str; 1024] = [
     "ton", "yn", "ala", "nit", "con", "vel", "ero", "tal", "yr", "edi", "ble", "ae", "ye", "mer",
 ];
 
+/// Estimated phoneme count of each token's text, indexed like `TOKENS`.
+pub const TOKEN_PHONEMES: [u8; 1024] = [
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 2, 2, 2, 2, 2, 1, 1, 1, 2, 2, 2, 2, 1, 2,
+    2, 2, 2, 3, 2, 2, 1, 2, 2, 2, 2, 2, 1, 2, 2, 2, 2, 2, 2, 2, 1, 1, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2,
+    3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3,
+    2, 1, 2, 1, 3, 2, 2, 2, 2, 3, 2, 2, 2, 2, 3, 2, 2, 3, 2, 2, 2, 2, 3, 1, 2, 2, 1, 3, 3, 2, 3, 2,
+    3, 3, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2, 3, 2, 2, 2, 3, 4, 2, 2, 3, 1, 3, 1, 2, 2, 3, 2, 3, 2, 3, 4,
+    3, 3, 2, 3, 4, 2, 3, 4, 3, 2, 3, 2, 2, 3, 2, 3, 2, 3, 3, 2, 3, 2, 2, 2, 5, 3, 3, 3, 2, 3, 3, 2,
+    2, 2, 4, 2, 3, 3, 3, 3, 3, 3, 2, 2, 2, 3, 3, 1, 3, 2, 3, 1, 3, 2, 3, 3, 3, 2, 2, 3, 3, 3, 3, 3,
+    3, 3, 3, 2, 3, 3, 3, 2, 2, 3, 3, 4, 3, 2, 3, 2, 3, 2, 2, 4, 3, 3, 3, 3, 3, 3, 2, 2, 3, 3, 3, 3,
+    1, 1, 1, 1, 1, 2, 1, 2, 1, 1, 1, 2, 1, 2, 2, 1, 2, 2, 1, 2, 1, 2, 1, 2, 2, 1, 1, 3, 3, 1, 2, 3,
+    2, 3, 2, 2, 1, 4, 2, 1, 2, 1, 3, 3, 2, 2, 4, 1, 2, 1, 2, 5, 1, 2, 2, 1, 2, 2, 3, 3, 4, 2, 3, 2,
+    3, 3, 1, 2, 2, 3, 3, 1, 3, 3, 5, 2, 2, 2, 3, 1, 3, 4, 3, 3, 2, 2, 3, 2, 1, 1, 2, 2, 2, 2, 2, 1,
+    2, 2, 3, 3, 2, 3, 3, 1, 2, 1, 2, 1, 3, 3, 3, 3, 3, 2, 2, 2, 4, 4, 6, 2, 3, 4, 3, 3, 3, 3, 1, 3,
+    2, 3, 3, 4, 3, 1, 2, 3, 2, 3, 2, 3, 3, 3, 3, 2, 2, 1, 1, 3, 3, 4, 3, 2, 1, 3, 2, 3, 2, 2, 1, 4,
+    1, 4, 4, 3, 2, 3, 1, 3, 4, 2, 3, 3, 2, 3, 4, 2, 3, 3, 3, 1, 3, 4, 3, 3, 1, 3, 3, 3, 3, 2, 3, 4,
+    4, 2, 3, 4, 3, 3, 2, 4, 3, 2, 2, 2, 3, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 2, 3, 2, 2, 3, 4, 2, 4, 3,
+    2, 2, 5, 3, 3, 2, 3, 3, 2, 2, 3, 3, 1, 1, 3, 3, 3, 3, 3, 2, 5, 4, 2, 3, 2, 3, 2, 4, 3, 4, 3, 3,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 2, 1, 2, 2, 2, 2, 2, 2, 2, 1, 2, 2, 2, 1, 2,
+    2, 2, 2, 2, 1, 2, 2, 2, 1, 2, 2, 2, 2, 2, 2, 1, 2, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 2, 1,
+    2, 2, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 3, 2, 2, 1, 2, 2, 2, 2, 2, 2, 2, 1, 2,
+    2, 1, 2, 2, 2, 2, 2, 2, 2, 2, 1, 3, 2, 2, 2, 2, 1, 3, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 1, 2, 2, 2,
+    2, 1, 2, 3, 4, 2, 2, 2, 1, 2, 2, 2, 2, 2, 2, 3, 2, 3, 1, 2, 3, 2, 3, 2, 3, 2, 2, 1, 2, 2, 2, 2,
+    1, 2, 1, 2, 3, 1, 3, 1, 2, 2, 2, 3, 3, 2, 2, 2, 2, 2, 2, 3, 3, 2, 3, 2, 3, 3, 2, 2, 3, 3, 3, 2,
+    3, 2, 2, 2, 2, 1, 3, 3, 3, 2, 1, 2, 2, 4, 3, 2, 3, 2, 2, 3, 3, 2, 3, 1, 2, 2, 3, 2, 2, 3, 2, 2,
+    2, 2, 2, 2, 3, 3, 2, 2, 3, 2, 2, 3, 3, 3, 3, 2, 3, 3, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 3, 2, 2, 2,
+    3, 2, 3, 2, 3, 3, 3, 1, 3, 3, 3, 3, 3, 2, 3, 2, 3, 2, 2, 1, 3, 3, 2, 1, 2, 3, 3, 2, 2, 3, 2, 2,
+    2, 2, 3, 2, 3, 2, 3, 3, 2, 2, 2, 3, 2, 3, 3, 2, 3, 3, 3, 2, 2, 2, 2, 2, 3, 3, 3, 2, 3, 3, 3, 2,
+    3, 3, 3, 2, 3, 3, 2, 2, 2, 2, 2, 2, 3, 3, 2, 2, 3, 1, 1, 3, 3, 2, 3, 2, 3, 3, 3, 2, 3, 3, 3, 3,
+    3, 3, 3, 3, 2, 3, 3, 2, 2, 3, 5, 3, 2, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 2, 3, 1, 3, 3, 2, 3,
+    2, 3, 2, 3, 3, 4, 3, 2, 3, 3, 2, 3, 2, 3, 3, 3, 2, 3, 4, 3, 3, 4, 3, 3, 3, 3, 2, 3, 2, 4, 3, 3,
+    3, 2, 2, 2, 3, 4, 2, 3, 2, 3, 2, 2, 3, 3, 3, 4, 2, 2, 2, 2, 3, 4, 3, 3, 2, 2, 3, 2, 3, 2, 2, 3,
+    3, 3, 3, 3, 3, 2, 3, 3, 2, 3, 3, 3, 3, 3, 3, 2, 3, 3, 3, 3, 3, 3, 3, 2, 3, 3, 3, 3, 3, 2, 2, 1,
+    3, 3, 3, 2, 3, 1, 2, 3, 2, 3, 3, 2, 3, 3, 2, 3, 2, 3, 3, 2, 3, 3, 3, 3, 3, 3, 2, 3, 3, 2, 2, 3,
+];
+
 /// Beginning token transitions.
 /// Format: (`token_id`, `cumulative_probability` as u8)
 pub const BEGIN_TRANSITIONS: [(u16, u8); 257] = [
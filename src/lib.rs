@@ -9,16 +9,49 @@
 //!
 //! This crate is not trying to be the most secure, fastest, or most
 //! entropy-efficient solution. The goal is simply readability.
+//!
+//! Words are generated from the output of a [`ReadableHasher`], such as
+//! [`StdHasher`]; implement the trait to use your own hash function.
+//!
+//! The `std` feature is on by default. Without it the crate is `no_std`
+//! and needs only `alloc`; functions that do I/O or use the standard
+//! library's hashers and collections are left out.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
-use std::hash::{DefaultHasher, Hasher};
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::hash::Hasher;
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::hash::DefaultHasher;
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
 
+#[cfg(feature = "hmac")]
+use hmac::{Hmac, KeyInit, Mac};
+#[cfg(feature = "sha512")]
+use sha2::{Digest, Sha512};
 #[cfg(feature = "shake256")]
 use sha3::Shake256;
 #[cfg(feature = "shake256")]
 use sha3::digest::{ExtendableOutput, Update as XofUpdate, XofReader};
 
+pub mod bits;
 pub mod english_word;
 mod english_word_data;
+pub mod readers;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "std")]
+pub use readers::ReadAdapter;
+pub use readers::{ChainReader, CountingReader, DequeReader, IterReader, RepeatReader};
 
 // ============================================================================
 // Core Traits
@@ -29,15 +62,64 @@ pub trait ByteReader {
     /// Read bytes into the destination buffer. Returns bytes read.
     fn read(&mut self, dest: &mut [u8]) -> usize;
 
-    /// Returns remaining bytes, or `None` if infinite.
+    /// Returns remaining bytes, or `None` if the length is not known.
+    ///
+    /// `None` covers infinite readers as well as finite streams whose end
+    /// has not been reached yet, so callers must not assume a `None`
+    /// reader ever runs out.
     fn remaining(&self) -> Option<usize>;
 }
 
 /// Trait for hashers that produce readable hashes.
+///
+/// Every `english_word_hash`-style function is generic over this trait:
+/// it starts from [`Default`], feeds the input through
+/// [`update`](Self::update) and generates the word from the bytes of the
+/// [`Reader`](Self::Reader) returned by [`finalize`](Self::finalize).
+/// Implement it to drive the words with your own hash or key derivation.
+///
+/// # Examples
+///
+/// A hasher that passes the input through unchanged, so the word is
+/// generated from the input bytes themselves:
+///
+/// ```
+/// use readable_hash::{english_word, english_word_hash, IterReader, ReadableHasher, SliceReader};
+///
+/// #[derive(Default)]
+/// struct IdentityHasher {
+///     bytes: Vec<u8>,
+/// }
+///
+/// impl ReadableHasher for IdentityHasher {
+///     type Reader = IterReader<std::vec::IntoIter<u8>>;
+///
+///     fn update(&mut self, data: &[u8]) {
+///         self.bytes.extend_from_slice(data);
+///     }
+///
+///     fn finalize(self) -> Self::Reader {
+///         IterReader::new(self.bytes.into_iter())
+///     }
+/// }
+///
+/// let input = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE];
+/// assert_eq!(
+///     english_word_hash::<IdentityHasher, _>(input),
+///     english_word::generate_word_with_target_len(&mut SliceReader::new(&input), input.len())
+/// );
+/// ```
 pub trait ReadableHasher: Default {
+    /// Stream of digest bytes. Report a finite length from
+    /// [`ByteReader::remaining`] for fixed-size digests and `None` for
+    /// extendable output, which callers then read only as far as they need.
     type Reader: ByteReader;
 
+    /// Add `data` to the input. It may be called several times; the digest
+    /// must depend only on the concatenated data.
     fn update(&mut self, data: &[u8]);
+
+    /// Finish hashing and return the digest bytes.
     fn finalize(self) -> Self::Reader;
 }
 
@@ -45,11 +127,19 @@ pub trait ReadableHasher: Default {
 // StdHasher (8 bytes output)
 // ============================================================================
 
+#[cfg(feature = "std")]
 #[derive(Default)]
 pub struct StdHasher<H: Hasher + Default = DefaultHasher> {
     hasher: H,
 }
 
+// Without `std` there is no `DefaultHasher` to default to.
+#[cfg(not(feature = "std"))]
+#[derive(Default)]
+pub struct StdHasher<H: Hasher + Default> {
+    hasher: H,
+}
+
 impl<H: Hasher + Default> ReadableHasher for StdHasher<H> {
     type Reader = StdHasherReader;
 
@@ -104,9 +194,7 @@ impl ReadableHasher for Shake256Hasher {
     }
 
     fn finalize(self) -> Self::Reader {
-        Shake256Reader {
-            reader: self.hasher.finalize_xof(),
-        }
+        Shake256Reader::new(self.hasher.finalize_xof())
     }
 }
 
@@ -115,6 +203,14 @@ pub struct Shake256Reader {
     reader: sha3::Shake256Reader,
 }
 
+#[cfg(feature = "shake256")]
+impl Shake256Reader {
+    /// Squeeze an already finalized SHAKE256 state on demand.
+    pub fn new(reader: sha3::Shake256Reader) -> Self {
+        Self { reader }
+    }
+}
+
 #[cfg(feature = "shake256")]
 impl ByteReader for Shake256Reader {
     fn read(&mut self, dest: &mut [u8]) -> usize {
@@ -127,6 +223,120 @@ impl ByteReader for Shake256Reader {
     }
 }
 
+// ============================================================================
+// Sha512Hasher (64 bytes output)
+// ============================================================================
+
+/// SHA-512 hasher with 64 bytes of output.
+///
+/// Words read up to 64 digest bytes instead of the 8 of [`StdHasher`].
+/// Every hasher maps the same input to a different word, so switching
+/// hashers changes all existing readable hashes.
+#[cfg(feature = "sha512")]
+#[derive(Default)]
+pub struct Sha512Hasher {
+    hasher: Sha512,
+}
+
+#[cfg(feature = "sha512")]
+impl ReadableHasher for Sha512Hasher {
+    type Reader = Sha512Reader;
+
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.hasher, data);
+    }
+
+    fn finalize(self) -> Self::Reader {
+        Sha512Reader {
+            bytes: self.hasher.finalize().into(),
+            position: 0,
+        }
+    }
+}
+
+#[cfg(feature = "sha512")]
+pub struct Sha512Reader {
+    bytes: [u8; 64],
+    position: usize,
+}
+
+#[cfg(feature = "sha512")]
+impl ByteReader for Sha512Reader {
+    fn read(&mut self, dest: &mut [u8]) -> usize {
+        let available = 64 - self.position;
+        let bytes_to_read = dest.len().min(available);
+        dest[..bytes_to_read]
+            .copy_from_slice(&self.bytes[self.position..self.position + bytes_to_read]);
+        self.position += bytes_to_read;
+        bytes_to_read
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        Some(64 - self.position)
+    }
+}
+
+// ============================================================================
+// Blake3Hasher (infinite output)
+// ============================================================================
+
+#[cfg(feature = "blake3")]
+#[derive(Default)]
+pub struct Blake3Hasher {
+    hasher: blake3::Hasher,
+}
+
+#[cfg(feature = "blake3")]
+impl ReadableHasher for Blake3Hasher {
+    type Reader = Blake3Reader;
+
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    fn finalize(self) -> Self::Reader {
+        Blake3Reader::new(self.hasher.finalize_xof())
+    }
+}
+
+#[cfg(feature = "blake3")]
+pub struct Blake3Reader {
+    reader: blake3::OutputReader,
+}
+
+#[cfg(feature = "blake3")]
+impl Blake3Reader {
+    /// Squeeze an already finalized BLAKE3 state on demand.
+    ///
+    /// Only the bytes a generator reads are computed, so keyed or
+    /// key-derivation states can drive words of any length.
+    ///
+    /// # Examples
+    /// ```
+    /// use readable_hash::{english_word, Blake3Reader};
+    ///
+    /// let mut hasher = blake3::Hasher::new_derive_key("readable-hash example");
+    /// hasher.update(b"hello");
+    /// let word = english_word::generate_word_with_max_len(&mut Blake3Reader::new(hasher.finalize_xof()), 12);
+    /// assert!(word.len() <= 12);
+    /// ```
+    pub fn new(reader: blake3::OutputReader) -> Self {
+        Self { reader }
+    }
+}
+
+#[cfg(feature = "blake3")]
+impl ByteReader for Blake3Reader {
+    fn read(&mut self, dest: &mut [u8]) -> usize {
+        self.reader.fill(dest);
+        dest.len()
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        None
+    }
+}
+
 // ============================================================================
 // SliceReader - ByteReader for byte slices
 // ============================================================================
@@ -170,6 +380,7 @@ impl<'a> ByteReader for SliceReader<'a> {
 ///
 /// # Examples
 /// ```
+/// # #[cfg(feature = "std")] {
 /// use readable_hash::{english_word_hash, StdHasher};
 ///
 /// assert_eq!(english_word_hash::<StdHasher, _>("I"), "waged");
@@ -178,6 +389,7 @@ impl<'a> ByteReader for SliceReader<'a> {
 ///     english_word_hash::<StdHasher, _>("pneumonoultramicroscopicsilicovolcanoconiosis"),
 ///     "dummaricardemastria"
 /// );
+/// # }
 /// ```
 pub fn english_word_hash<H, T>(input: T) -> String
 where
@@ -192,16 +404,1875 @@ where
 
     let mut hasher = H::default();
     hasher.update(input_bytes);
-    let reader = hasher.finalize();
+    english_word_from_hasher(hasher, input_len)
+}
 
-    // For infinite readers, wrap with a length limiter
-    let bytes_limit = match reader.remaining() {
-        Some(_) => None,                // Finite: use all
-        None => Some(input_len.max(8)), // Infinite: limit to input length
-    };
+/// Generate english-like word hash in uppercase, for all-caps displays.
+///
+/// Only ASCII letters change case.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_hash_uppercase, StdHasher};
+///
+/// assert_eq!(readable_hash_uppercase::<StdHasher, _>("hello"), "HIRED");
+/// # }
+/// ```
+pub fn readable_hash_uppercase<H, T>(input: T) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let mut word = english_word_hash::<H, _>(input);
+    word.make_ascii_uppercase();
+    word
+}
 
-    let mut limited_reader = LimitedByteReader::new(reader, bytes_limit);
-    english_word::generate_word_with_target_len(&mut limited_reader, input_len)
+/// Generate english-like word hash with its first letter capitalized.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_hash_capitalized, StdHasher};
+///
+/// assert_eq!(readable_hash_capitalized::<StdHasher, _>("hello"), "Hired");
+/// # }
+/// ```
+pub fn readable_hash_capitalized<H, T>(input: T) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let mut word = english_word_hash::<H, _>(input);
+    english_word::capitalize_first_letter(&mut word);
+    word
+}
+
+/// Generate english-like word hash of exactly `exact_len` bytes, for keys
+/// that should all have the same width.
+///
+/// Reads the digest like [`english_word_hash`], but picks tokens with
+/// [`english_word::generate_word_in_range`] so the word ends at
+/// `exact_len`. When no end token lands on `exact_len` exactly, the word
+/// from [`english_word::generate_word_with_target_len`] is returned
+/// instead. It is at least `exact_len` bytes long, and its end token is
+/// still chosen by the digest, so it may be a few bytes longer. A word can
+/// also fall short when the digest runs out, which finite hashers make more
+/// likely for large `exact_len`. Check the length if it must be exact.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash_fixed, StdHasher};
+///
+/// assert_eq!(english_word_hash_fixed::<StdHasher, _>("hello", 6).len(), 6);
+/// # }
+/// ```
+pub fn english_word_hash_fixed<H, T>(input: T, exact_len: usize) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input_bytes = input.as_ref();
+    if input_bytes.is_empty() || exact_len == 0 {
+        return String::new();
+    }
+
+    let mut hasher = H::default();
+    hasher.update(input_bytes);
+    let digest = read_to_end(limit_reader(hasher.finalize(), exact_len));
+    let word =
+        english_word::generate_word_in_range(&mut SliceReader::new(&digest), exact_len, exact_len);
+    if word.len() == exact_len {
+        return word;
+    }
+    english_word::generate_word_with_target_len(&mut SliceReader::new(&digest), exact_len)
+}
+
+/// A readable hash kept apart from other strings.
+///
+/// Displays, and with the `serde` feature serializes, as the plain hash
+/// string. Any string converts into a `ReadableHash`; nothing is validated,
+/// so hashes stored elsewhere can be read back as they are.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{ReadableHash, StdHasher};
+///
+/// let hash = ReadableHash::new::<StdHasher, _>("I");
+/// assert_eq!(hash.to_string(), "waged");
+/// assert_eq!(hash, ReadableHash::from("waged".to_string()));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReadableHash(String);
+
+impl ReadableHash {
+    /// [`english_word_hash`] of `input`.
+    pub fn new<H, T>(input: T) -> Self
+    where
+        H: ReadableHasher,
+        T: AsRef<[u8]>,
+    {
+        Self(english_word_hash::<H, _>(input))
+    }
+
+    /// The hash as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Unwrap the hash string.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for ReadableHash {
+    fn from(hash: String) -> Self {
+        Self(hash)
+    }
+}
+
+impl AsRef<str> for ReadableHash {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for ReadableHash {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ReadableHash {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ReadableHash {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Error returned by [`try_readable_hash`] when the input exceeds the
+/// allowed length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputTooLong {
+    /// Length of the rejected input in bytes.
+    pub len: usize,
+    /// Maximum allowed length in bytes.
+    pub max_len: usize,
+}
+
+impl core::fmt::Display for InputTooLong {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "input is {} bytes long, more than the allowed {} bytes",
+            self.len, self.max_len
+        )
+    }
+}
+
+impl core::error::Error for InputTooLong {}
+
+/// Generate english-like word hash, refusing inputs longer than `max_len`
+/// bytes.
+///
+/// A guard for services hashing untrusted input, where a huge input (say,
+/// a whole file passed as a string) should be rejected rather than hashed.
+/// Otherwise identical to [`english_word_hash`].
+///
+/// # Errors
+///
+/// Returns [`InputTooLong`] if the input is longer than `max_len` bytes.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash, try_readable_hash, StdHasher};
+///
+/// assert_eq!(
+///     try_readable_hash::<StdHasher, _>("hello", 16),
+///     Ok(english_word_hash::<StdHasher, _>("hello"))
+/// );
+/// assert!(try_readable_hash::<StdHasher, _>("hello", 4).is_err());
+/// # }
+/// ```
+pub fn try_readable_hash<H, T>(input: T, max_len: usize) -> Result<String, InputTooLong>
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let len = input.as_ref().len();
+    if len > max_len {
+        return Err(InputTooLong { len, max_len });
+    }
+    Ok(english_word_hash::<H, _>(input))
+}
+
+/// Generate english-like word hash of a JSON document, ignoring formatting.
+///
+/// The text is parsed and re-serialized canonically before hashing: object
+/// keys are sorted and insignificant whitespace is dropped, so documents
+/// that differ only in layout or key order hash the same. Numbers keep
+/// their parsed form, so `1` and `1.0` still hash differently.
+///
+/// # Errors
+///
+/// Returns an error if `json` is not valid JSON.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{readable_hash_json_str, StdHasher};
+///
+/// assert_eq!(
+///     readable_hash_json_str::<StdHasher>(r#"{"b": 1, "a": [true, null]}"#).unwrap(),
+///     readable_hash_json_str::<StdHasher>(r#"{"a":[true,null],"b":1}"#).unwrap()
+/// );
+/// ```
+#[cfg(feature = "json")]
+pub fn readable_hash_json_str<H: ReadableHasher>(json: &str) -> Result<String, serde_json::Error> {
+    let value: serde_json::Value = serde_json::from_str(json)?;
+    let mut canonical = Vec::new();
+    write_canonical_json(&value, &mut canonical)?;
+    Ok(english_word_hash::<H, _>(canonical))
+}
+
+/// Serialize `value` compactly with object keys sorted.
+///
+/// Keys are sorted here rather than relying on `serde_json::Map`, whose
+/// order depends on the `preserve_order` feature.
+#[cfg(feature = "json")]
+fn write_canonical_json(value: &serde_json::Value, out: &mut Vec<u8>) -> serde_json::Result<()> {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|left, right| left.0.cmp(right.0));
+            out.push(b'{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                serde_json::to_writer(&mut *out, key)?;
+                out.push(b':');
+                write_canonical_json(item, out)?;
+            }
+            out.push(b'}');
+        }
+        serde_json::Value::Array(items) => {
+            out.push(b'[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                write_canonical_json(item, out)?;
+            }
+            out.push(b']');
+        }
+        scalar => serde_json::to_writer(&mut *out, scalar)?,
+    }
+    Ok(())
+}
+
+/// Generate english-like word hash of a value's bincode encoding.
+///
+/// The value is serialized with bincode 2 using
+/// `bincode::config::standard().with_little_endian().with_fixed_int_encoding()`:
+/// integers are little-endian and fixed width, and lengths are `u64`. This
+/// encoding depends only on the value, so equal values always hash the
+/// same. Serialization follows the value's `Serialize` impl, so collections
+/// with an unspecified iteration order, like `HashMap`, can still hash
+/// differently; use `BTreeMap` or sort first.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be serialized.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{readable_hash_bincode, StdHasher};
+///
+/// let word = readable_hash_bincode::<StdHasher, _>(&("user", 42u32)).unwrap();
+/// assert_eq!(word, readable_hash_bincode::<StdHasher, _>(&("user".to_string(), 42u32)).unwrap());
+/// ```
+#[cfg(feature = "bincode")]
+pub fn readable_hash_bincode<H, T>(value: &T) -> Result<String, bincode::error::EncodeError>
+where
+    H: ReadableHasher,
+    T: serde::Serialize + ?Sized,
+{
+    let config = bincode::config::standard()
+        .with_little_endian()
+        .with_fixed_int_encoding();
+    let encoded = bincode::serde::encode_to_vec(value, config)?;
+    Ok(english_word_hash::<H, _>(encoded))
+}
+
+/// Memoized results of [`readable_hash_cached`], keyed by hasher type and
+/// input.
+#[cfg(feature = "cache")]
+type HashCache = std::sync::Mutex<std::collections::HashMap<(std::any::TypeId, Vec<u8>), String>>;
+
+#[cfg(feature = "cache")]
+static HASH_CACHE: std::sync::OnceLock<HashCache> = std::sync::OnceLock::new();
+
+/// Generate english-like word hash, memoizing the result.
+///
+/// Meant for hot paths that hash a small, fixed set of inputs: repeated
+/// calls with the same input and hasher return the stored word without
+/// hashing again. The cache is global, shared between threads and never
+/// evicts, so it grows with every distinct input. The result is identical
+/// to [`english_word_hash`].
+///
+/// # Panics
+///
+/// Panics if another thread panicked while holding the cache lock.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word_hash, readable_hash_cached, StdHasher};
+///
+/// assert_eq!(
+///     readable_hash_cached::<StdHasher, _>("hello"),
+///     english_word_hash::<StdHasher, _>("hello")
+/// );
+/// ```
+#[cfg(feature = "cache")]
+pub fn readable_hash_cached<H, T>(input: T) -> String
+where
+    H: ReadableHasher + 'static,
+    T: AsRef<[u8]>,
+{
+    let key = (std::any::TypeId::of::<H>(), input.as_ref().to_vec());
+    let cache = HASH_CACHE.get_or_init(HashCache::default);
+    if let Some(word) = cache.lock().expect("Hash cache lock poisoned").get(&key) {
+        return word.clone();
+    }
+    // Hash without holding the lock; a concurrent miss computes the same word
+    let word = english_word_hash::<H, _>(&key.1);
+    cache
+        .lock()
+        .expect("Hash cache lock poisoned")
+        .insert(key, word.clone());
+    word
+}
+
+/// Number of results memoized by [`readable_hash_cached`].
+///
+/// # Panics
+///
+/// Panics if another thread panicked while holding the cache lock.
+#[cfg(feature = "cache")]
+pub fn readable_hash_cache_len() -> usize {
+    HASH_CACHE.get().map_or(0, |cache| {
+        cache.lock().expect("Hash cache lock poisoned").len()
+    })
+}
+
+/// Generate english-like word hash, ignoring surrounding ASCII whitespace.
+///
+/// Useful for user-entered input, where a trailing space or newline should
+/// not change the result. Unlike [`english_word_hash`], `"hello "` and
+/// `"hello"` hash the same.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash, readable_hash_trimmed_input, StdHasher};
+///
+/// assert_eq!(
+///     readable_hash_trimmed_input::<StdHasher, _>(" hello\n"),
+///     english_word_hash::<StdHasher, _>("hello")
+/// );
+/// # }
+/// ```
+pub fn readable_hash_trimmed_input<H, T>(input: T) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    english_word_hash::<H, _>(input.as_ref().trim_ascii())
+}
+
+/// Write the english-like word hash of each input to `out`.
+///
+/// Hashes are written one at a time, separated by `separator`, without
+/// collecting them first. No separator follows the last hash, so splitting
+/// the output on `separator` yields exactly one hash per input.
+///
+/// # Errors
+///
+/// Returns any error produced while writing to `out`.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{readable_hash_batch_write, StdHasher};
+///
+/// let mut out = Vec::new();
+/// readable_hash_batch_write::<StdHasher, _, _>(&["hello", "world"], &mut out, b'\n').unwrap();
+/// assert_eq!(out.split(|byte| *byte == b'\n').count(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn readable_hash_batch_write<H, T, W>(inputs: &[T], mut out: W, separator: u8) -> io::Result<()>
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+    W: Write,
+{
+    for (i, input) in inputs.iter().enumerate() {
+        if i > 0 {
+            out.write_all(&[separator])?;
+        }
+        out.write_all(english_word_hash::<H, _>(input).as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Generate english-like word hash of an integer in big-endian byte order.
+///
+/// The value is widened to `u128` and its 16 big-endian bytes are hashed,
+/// so the result does not depend on the platform or on the integer type
+/// passed in.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_hash_be, StdHasher};
+///
+/// assert_eq!(
+///     readable_hash_be::<StdHasher, _>(42u8),
+///     readable_hash_be::<StdHasher, _>(42u64)
+/// );
+/// # }
+/// ```
+pub fn readable_hash_be<H, T>(value: T) -> String
+where
+    H: ReadableHasher,
+    T: Into<u128>,
+{
+    english_word_hash::<H, _>(value.into().to_be_bytes())
+}
+
+/// Generate english-like word hash of an integer in little-endian byte
+/// order.
+///
+/// Like [`readable_hash_be`], but hashes the 16 little-endian bytes of the
+/// widened value, for protocols that specify little-endian encoding. Both
+/// agree only for values whose byte representation is symmetric, like 0.
+pub fn readable_hash_le<H, T>(value: T) -> String
+where
+    H: ReadableHasher,
+    T: Into<u128>,
+{
+    english_word_hash::<H, _>(value.into().to_le_bytes())
+}
+
+/// Generate english-like word hash of key/value entries, such as
+/// environment variables or a config map.
+///
+/// Entries are sorted by key (then value) before hashing, so the result
+/// does not depend on iteration or insertion order. Each key and value is
+/// prefixed with its length as a little-endian `u64`, so `("ab", "c")` and
+/// `("a", "bc")` hash differently. Accepts a `&BTreeMap<String, String>`,
+/// a `&HashMap`, or any iterator of pairs.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::BTreeMap;
+/// use readable_hash::{readable_hash_map, StdHasher};
+///
+/// let mut map = BTreeMap::new();
+/// map.insert("HOME".to_string(), "/root".to_string());
+/// map.insert("SHELL".to_string(), "/bin/sh".to_string());
+///
+/// assert_eq!(
+///     readable_hash_map::<StdHasher, _, _, _>(&map),
+///     readable_hash_map::<StdHasher, _, _, _>([("SHELL", "/bin/sh"), ("HOME", "/root")])
+/// );
+/// # }
+/// ```
+pub fn readable_hash_map<H, I, K, V>(entries: I) -> String
+where
+    H: ReadableHasher,
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<[u8]>,
+    V: AsRef<[u8]>,
+{
+    let mut sorted_entries: Vec<(K, V)> = entries.into_iter().collect();
+    sorted_entries.sort_by(|left, right| {
+        (left.0.as_ref(), left.1.as_ref()).cmp(&(right.0.as_ref(), right.1.as_ref()))
+    });
+
+    let mut hasher = H::default();
+    let mut input_len = 0;
+    for (key, value) in &sorted_entries {
+        for field in [key.as_ref(), value.as_ref()] {
+            hasher.update(&(field.len() as u64).to_le_bytes());
+            hasher.update(field);
+            input_len += field.len() + 8;
+        }
+    }
+    english_word_from_hasher(hasher, input_len)
+}
+
+/// Number of digest bytes shown in the hex tag of [`readable_hash_tagged`].
+const TAG_BYTES: usize = 3;
+
+/// Generate english-like word hash together with a short hex tag.
+///
+/// Both come from one digest: the word is [`english_word_hash`] and the tag
+/// is the first 6 lowercase hex characters of the digest, handy for exact
+/// lookups next to a name people can read. An empty input gives an empty
+/// word but still has a tag.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash, readable_hash_tagged, StdHasher};
+///
+/// let (word, tag) = readable_hash_tagged::<StdHasher, _>("hello");
+/// assert_eq!(word, english_word_hash::<StdHasher, _>("hello"));
+/// assert_eq!(tag.len(), 6);
+/// # }
+/// ```
+pub fn readable_hash_tagged<H, T>(input: T) -> (String, String)
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let (digest, word) = digest_and_word::<H>(input.as_ref());
+    let tag = digest
+        .iter()
+        .take(TAG_BYTES)
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    (word, tag)
+}
+
+/// Number of leading digest bytes encoded by [`sortable_readable_key`].
+const SORT_PREFIX_BYTES: usize = 5;
+
+/// Digits of the sortable prefix, in ascending ASCII order.
+const SORT_DIGITS: &[u8; 32] = b"0123456789abcdefghijklmnopqrstuv";
+
+/// Generate a key that sorts by digest while staying readable, e.g.
+/// `"aol80aen-waged"`.
+///
+/// The key starts with the first 5 digest bytes as 8 base-32 digits whose
+/// ASCII order matches the byte order, followed by `-` and the
+/// [`english_word_hash`] of the input. Sorting keys as strings therefore
+/// sorts them by digest, which scatters inputs evenly across a key space;
+/// the order has nothing to do with the order of the inputs. An empty input
+/// has an empty word, so its key ends with the `-`.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash, sortable_readable_key, StdHasher};
+///
+/// let key = sortable_readable_key::<StdHasher, _>("hello");
+/// let (prefix, word) = key.split_once('-').unwrap();
+/// assert_eq!(prefix.len(), 8);
+/// assert_eq!(word, english_word_hash::<StdHasher, _>("hello"));
+/// # }
+/// ```
+pub fn sortable_readable_key<H, T>(input: T) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let (digest, word) = digest_and_word::<H>(input.as_ref());
+    let mut high_bytes = [0u8; 8];
+    let prefix_len = digest.len().min(SORT_PREFIX_BYTES);
+    high_bytes[3..3 + prefix_len].copy_from_slice(&digest[..prefix_len]);
+    let prefix_value = u64::from_be_bytes(high_bytes);
+
+    let mut key: String = (0..8)
+        .rev()
+        .map(|i| char::from(SORT_DIGITS[((prefix_value >> (i * 5)) & 0x1F) as usize]))
+        .collect();
+    key.push('-');
+    key.push_str(&word);
+    key
+}
+
+/// Generate a readable hash by mapping each digest byte through `table`.
+///
+/// Byte `b` of the digest becomes `table[b]`, so the output has one entry
+/// per digest byte: 8 for [`StdHasher`], or a prefix of infinite outputs
+/// bounded like [`english_word_hash`]. The table is not validated. Entries
+/// are concatenated without separators, so the output can only be split
+/// back into bytes when no entry is a prefix of another.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_hash_with_table, StdHasher};
+///
+/// let names: Vec<String> = (0..=255).map(|byte| format!("{byte:02x}")).collect();
+/// let table: [&str; 256] = std::array::from_fn(|index| names[index].as_str());
+/// let hex = readable_hash_with_table::<StdHasher, _>("hello", &table);
+/// assert_eq!(hex.len(), 16);
+/// # }
+/// ```
+pub fn readable_hash_with_table<H, T>(input: T, table: &[&str; 256]) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input_bytes = input.as_ref();
+    read_to_end(hashed_reader::<H>(input_bytes, input_bytes.len()))
+        .into_iter()
+        .map(|byte| table[usize::from(byte)])
+        .collect()
+}
+
+/// Hash `input` and return the digest bytes with the word hash made from
+/// them.
+///
+/// The digest is what [`english_word_hash`] reads: the full output of
+/// finite hashers, or the bounded prefix of infinite ones.
+fn digest_and_word<H: ReadableHasher>(input: &[u8]) -> (Vec<u8>, String) {
+    let digest = read_to_end(hashed_reader::<H>(input, input.len()));
+    let word = if input.is_empty() {
+        String::new()
+    } else {
+        english_word::generate_word_with_target_len(&mut SliceReader::new(&digest), input.len())
+    };
+    (digest, word)
+}
+
+/// Read everything `reader` produces. `reader` must be finite.
+fn read_to_end<R: ByteReader>(mut reader: R) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut buffer = [0u8; 64];
+    loop {
+        let bytes_read = reader.read(&mut buffer);
+        if bytes_read == 0 {
+            break;
+        }
+        bytes.extend_from_slice(&buffer[..bytes_read]);
+    }
+    bytes
+}
+
+/// Generate english-like word hash after hashing `rounds` times.
+///
+/// The first round hashes the input; every further round hashes the
+/// previous digest, as [`english_word_hash`] would read it. The word comes
+/// from the final digest, so `rounds == 1` (or `0`) equals
+/// [`english_word_hash`]. Each round multiplies the cost of guessing inputs
+/// from words, which makes this mild key stretching. It is not memory-hard
+/// and much weaker than Argon2 or scrypt; use those for passwords.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash, readable_hash_rounds, StdHasher};
+///
+/// assert_eq!(
+///     readable_hash_rounds::<StdHasher, _>("hello", 1),
+///     english_word_hash::<StdHasher, _>("hello")
+/// );
+/// assert_ne!(
+///     readable_hash_rounds::<StdHasher, _>("hello", 1000),
+///     english_word_hash::<StdHasher, _>("hello")
+/// );
+/// # }
+/// ```
+pub fn readable_hash_rounds<H, T>(input: T, rounds: u32) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input_bytes = input.as_ref();
+    let mut hasher = H::default();
+    hasher.update(input_bytes);
+    for _ in 1..rounds {
+        let digest = read_to_end(limit_reader(hasher.finalize(), input_bytes.len()));
+        hasher = H::default();
+        hasher.update(&digest);
+    }
+    english_word_from_hasher(hasher, input_bytes.len())
+}
+
+/// Generate `word_count` english-like words from `input`, joined with
+/// `separator`.
+///
+/// A single word hash may carry only a few bytes of the digest; every
+/// added word reads 4 more, so more words make collisions between inputs
+/// less likely. The input is hashed into 4 bytes per word, rehashing finite
+/// hashers with a block counter as needed, and the words are generated from
+/// that stream in order like [`english_word::generate_passphrase`], so a
+/// longer phrase starts with the shorter one. An empty input gives an
+/// empty string, as it does for [`english_word_hash`].
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash_words, StdHasher};
+///
+/// let two = english_word_hash_words::<StdHasher, _>("hello", 2, "-");
+/// assert_eq!(two, english_word_hash_words::<StdHasher, _>("hello", 2, "-"));
+/// assert!(two.starts_with(&english_word_hash_words::<StdHasher, _>("hello", 1, "-")));
+/// assert_eq!(english_word_hash_words::<StdHasher, _>("", 2, "-"), "");
+/// # }
+/// ```
+pub fn english_word_hash_words<H, T>(input: T, word_count: usize, separator: &str) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input_bytes = input.as_ref();
+    if input_bytes.is_empty() {
+        return String::new();
+    }
+    let entropy = hashed_bytes::<H>(input_bytes, word_count * 4);
+    english_word::generate_passphrase(&mut SliceReader::new(&entropy), word_count, separator)
+}
+
+/// Digest bytes of [`readable_hash_nonced`] that depend on the input only.
+const NONCED_PREFIX_BYTES: usize = 4;
+
+/// Digest bytes of [`readable_hash_nonced`] that depend on the nonce too.
+const NONCED_TAIL_BYTES: usize = 3;
+
+/// Generate english-like word hash that is shared by the input and varied
+/// by `nonce`.
+///
+/// The first four tokens come from a digest of the input alone, so every
+/// nonce of the same input shares that prefix. The remaining tokens come
+/// from a digest of the nonce and the input, which tells occurrences of the
+/// same event apart, e.g. when deduplicating logs. Different nonces give
+/// different words with high probability, but not always: the tail has only
+/// three tokens, and a word whose prefix reaches a token without middle
+/// continuations ends before the tail.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_hash_nonced, StdHasher};
+///
+/// let first = readable_hash_nonced::<StdHasher, _>("disk full", 1);
+/// let second = readable_hash_nonced::<StdHasher, _>("disk full", 2);
+/// assert_ne!(first, second);
+/// assert_eq!(first[..3], second[..3]);
+/// # }
+/// ```
+pub fn readable_hash_nonced<H, T>(input: T, nonce: u64) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input_bytes = input.as_ref();
+    let mut entropy = hashed_bytes::<H>(input_bytes, NONCED_PREFIX_BYTES);
+    let mut nonced_input = nonce.to_le_bytes().to_vec();
+    nonced_input.extend_from_slice(input_bytes);
+    entropy.extend(hashed_bytes::<H>(&nonced_input, NONCED_TAIL_BYTES));
+    english_word::generate_word(&mut SliceReader::new(&entropy))
+}
+
+/// Generate english-like word hash of `input` in the domain of `salt`.
+///
+/// The salt length, the salt and then the input are hashed, so different
+/// salts give independent words for the same input, and moving bytes
+/// between salt and input changes the word. Subsystems sharing logs can
+/// each use their own salt to keep their hashes apart. An empty salt gives
+/// [`english_word_hash`] unchanged.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash, readable_hash_salted, StdHasher};
+///
+/// assert_ne!(
+///     readable_hash_salted::<StdHasher, _>("x", b"a"),
+///     readable_hash_salted::<StdHasher, _>("x", b"b")
+/// );
+/// assert_eq!(readable_hash_salted::<StdHasher, _>("x", b""), english_word_hash::<StdHasher, _>("x"));
+/// # }
+/// ```
+pub fn readable_hash_salted<H, T>(input: T, salt: &[u8]) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input_bytes = input.as_ref();
+    if input_bytes.is_empty() {
+        return String::new();
+    }
+
+    let mut hasher = H::default();
+    if !salt.is_empty() {
+        hasher.update(&(salt.len() as u64).to_le_bytes());
+        hasher.update(salt);
+    }
+    hasher.update(input_bytes);
+    english_word_from_hasher(hasher, input_bytes.len())
+}
+
+/// Generate english-like word hash of `input` keyed with `key`.
+///
+/// The word is generated from the HMAC-SHA256 tag of `input` like
+/// [`english_word_hash`] generates it from a digest, so it cannot be
+/// reproduced or forged without `key`. A word is short, so it resists
+/// guessing far less than the full tag; use it for readable tags, not as
+/// the only check of authenticity. An empty input gives an empty string.
+///
+/// # Examples
+/// ```
+/// use readable_hash::readable_hash_hmac;
+///
+/// let tag = readable_hash_hmac(b"secret", b"hello");
+/// assert_eq!(tag, readable_hash_hmac(b"secret", b"hello"));
+/// assert_ne!(tag, readable_hash_hmac(b"other secret", b"hello"));
+/// ```
+#[cfg(feature = "hmac")]
+pub fn readable_hash_hmac(key: &[u8], input: &[u8]) -> String {
+    if input.is_empty() {
+        return String::new();
+    }
+    let mut mac =
+        Hmac::<sha2::Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    Mac::update(&mut mac, input);
+    let tag = mac.finalize().into_bytes();
+    english_word::generate_word_with_target_len(&mut SliceReader::new(&tag), input.len())
+}
+
+/// Explain [`english_word_hash`] token by token.
+///
+/// Returns each token of the word hash with the digest byte that selected
+/// it, in digest order. Joining the token texts gives the word hash. See
+/// [`english_word::explain_word_with_target_len`] for how the end token is
+/// reported.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash, readable_hash_explain, StdHasher};
+///
+/// let explained = readable_hash_explain::<StdHasher, _>("I");
+/// let word: String = explained.iter().map(|(text, _)| *text).collect();
+/// assert_eq!(word, english_word_hash::<StdHasher, _>("I"));
+/// # }
+/// ```
+pub fn readable_hash_explain<H, T>(input: T) -> Vec<(&'static str, u8)>
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input_bytes = input.as_ref();
+    let mut hasher = H::default();
+    hasher.update(input_bytes);
+    explain_from_hasher(hasher, input_bytes.len())
+}
+
+/// Finalize `hasher` and explain the word for an input of `input_len`
+/// bytes, like [`english_word_from_hasher`].
+fn explain_from_hasher<H: ReadableHasher>(hasher: H, input_len: usize) -> Vec<(&'static str, u8)> {
+    if input_len == 0 {
+        return Vec::new();
+    }
+    let mut limited_reader = limit_reader(hasher.finalize(), input_len);
+    english_word::explain_word_with_target_len(&mut limited_reader, input_len)
+}
+
+/// Generate english-like word hash with `separator` between its tokens.
+///
+/// The tokens are those of [`readable_hash_explain`], so an empty
+/// separator gives exactly [`english_word_hash`]. The separator only goes
+/// between tokens, never before the first or after the last.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash, readable_hash_with_separator, StdHasher};
+///
+/// let separated = readable_hash_with_separator::<StdHasher, _>("hello", "-");
+/// assert_eq!(separated.replace('-', ""), english_word_hash::<StdHasher, _>("hello"));
+/// assert!(!separated.ends_with('-'));
+/// # }
+/// ```
+pub fn readable_hash_with_separator<H, T>(input: T, separator: &str) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    readable_hash_explain::<H, _>(input)
+        .into_iter()
+        .map(|(text, _)| text)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Generate english-like word hash from everything read from `reader`.
+///
+/// The input is hashed in chunks, so it never has to fit in memory. The
+/// result is identical to calling [`english_word_hash`] on the full input.
+///
+/// # Errors
+///
+/// Returns any error produced by the underlying reader.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word_hash, readable_hash_reader, StdHasher};
+///
+/// let word = readable_hash_reader::<StdHasher, _>(&b"hello"[..]).unwrap();
+/// assert_eq!(word, english_word_hash::<StdHasher, _>("hello"));
+/// ```
+#[cfg(feature = "std")]
+pub fn readable_hash_reader<H, R>(reader: R) -> io::Result<String>
+where
+    H: ReadableHasher,
+    R: Read,
+{
+    readable_hash_reader_counted::<H, _>(reader).map(|(word, _)| word)
+}
+
+/// Generate english-like word hash from `reader` and count the bytes read.
+///
+/// Same as [`readable_hash_reader`], but also returns the total number of
+/// bytes hashed, so the input does not need a second pass to be measured.
+///
+/// # Errors
+///
+/// Returns any error produced by the underlying reader.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{readable_hash_reader_counted, StdHasher};
+///
+/// let (_, byte_count) = readable_hash_reader_counted::<StdHasher, _>(&b"hello"[..]).unwrap();
+/// assert_eq!(byte_count, 5);
+/// ```
+#[cfg(feature = "std")]
+pub fn readable_hash_reader_counted<H, R>(mut reader: R) -> io::Result<(String, u64)>
+where
+    H: ReadableHasher,
+    R: Read,
+{
+    let mut hasher = H::default();
+    let mut buffer = [0u8; 8192];
+    let mut input_len: u64 = 0;
+    loop {
+        let bytes_read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(bytes_read) => bytes_read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        hasher.update(&buffer[..bytes_read]);
+        input_len += bytes_read as u64;
+    }
+    Ok((
+        english_word_from_hasher(hasher, input_len as usize),
+        input_len,
+    ))
+}
+
+/// An [`io::Write`] sink that hashes everything written to it.
+///
+/// Streams can be hashed with [`io::copy`] instead of being collected
+/// first. Writing an input in any number of chunks gives the same hash as
+/// passing it whole to [`english_word_hash`].
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word_hash, HashWriter, StdHasher};
+/// use std::io::Write;
+///
+/// let mut writer = HashWriter::<StdHasher>::new();
+/// writer.write_all(b"hel")?;
+/// writer.write_all(b"lo")?;
+/// assert_eq!(writer.finish_words(), english_word_hash::<StdHasher, _>("hello"));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub struct HashWriter<H: ReadableHasher> {
+    hasher: H,
+    len: usize,
+}
+
+#[cfg(feature = "std")]
+impl<H: ReadableHasher> HashWriter<H> {
+    pub fn new() -> Self {
+        Self {
+            hasher: H::default(),
+            len: 0,
+        }
+    }
+
+    /// The [`english_word_hash`] of everything written.
+    pub fn finish_words(self) -> String {
+        english_word_from_hasher(self.hasher, self.len)
+    }
+
+    /// The tokens of [`finish_words`](Self::finish_words) joined by `-`,
+    /// like [`readable_hash_with_separator`] with `"-"`.
+    pub fn finish_syllables(self) -> String {
+        explain_from_hasher(self.hasher, self.len)
+            .into_iter()
+            .map(|(text, _)| text)
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<H: ReadableHasher> Default for HashWriter<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<H: ReadableHasher> Write for HashWriter<H> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.hasher.update(buf);
+        self.len += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Error returned by [`readable_hash_reader_limited`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum LimitedReadError {
+    /// The stream is longer than allowed.
+    LimitExceeded {
+        /// Maximum allowed number of bytes.
+        max_bytes: u64,
+    },
+    /// The underlying reader failed.
+    Io(io::Error),
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for LimitedReadError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::LimitExceeded { max_bytes } => {
+                write!(formatter, "input is longer than {max_bytes} bytes")
+            }
+            Self::Io(error) => write!(formatter, "failed to read input: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for LimitedReadError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::LimitExceeded { .. } => None,
+            Self::Io(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for LimitedReadError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Generate english-like word hash from `reader`, giving up after
+/// `max_bytes`.
+///
+/// A guard for untrusted streams: hashing stops as soon as more than
+/// `max_bytes` bytes have been read, so an endless or huge stream cannot
+/// keep it busy. At most `max_bytes + 1` bytes are read. Under the limit
+/// the result is identical to [`readable_hash_reader`].
+///
+/// # Errors
+///
+/// Returns [`LimitedReadError::LimitExceeded`] if the stream is longer than
+/// `max_bytes`, or [`LimitedReadError::Io`] with any error produced by the
+/// underlying reader.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{readable_hash_reader_limited, LimitedReadError, StdHasher};
+///
+/// assert!(readable_hash_reader_limited::<StdHasher, _>(&b"hello"[..], 5).is_ok());
+///
+/// let error = readable_hash_reader_limited::<StdHasher, _>(&b"hello"[..], 4).unwrap_err();
+/// assert!(matches!(error, LimitedReadError::LimitExceeded { max_bytes: 4 }));
+/// ```
+#[cfg(feature = "std")]
+pub fn readable_hash_reader_limited<H, R>(
+    mut reader: R,
+    max_bytes: u64,
+) -> Result<String, LimitedReadError>
+where
+    H: ReadableHasher,
+    R: Read,
+{
+    let mut hasher = H::default();
+    let mut buffer = [0u8; 8192];
+    let mut input_len: u64 = 0;
+    loop {
+        // Read one byte past the limit at most, to tell whether it was crossed
+        let allowed = (max_bytes - input_len).saturating_add(1);
+        let read_len = buffer
+            .len()
+            .min(usize::try_from(allowed).unwrap_or(usize::MAX));
+        let bytes_read = match reader.read(&mut buffer[..read_len]) {
+            Ok(0) => break,
+            Ok(bytes_read) => bytes_read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error.into()),
+        };
+        input_len += bytes_read as u64;
+        if input_len > max_bytes {
+            return Err(LimitedReadError::LimitExceeded { max_bytes });
+        }
+        hasher.update(&buffer[..bytes_read]);
+    }
+    Ok(english_word_from_hasher(hasher, input_len as usize))
+}
+
+/// Generate english-like word hash of text from `reader`, ignoring line
+/// ending style.
+///
+/// CRLF and lone CR line endings are normalized to LF while streaming, so
+/// the same text hashes identically whether it was saved on Windows, old
+/// macOS or Unix. The result equals [`english_word_hash`] of the normalized
+/// text.
+///
+/// # Errors
+///
+/// Returns any error produced by the underlying reader.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{readable_hash_text_reader, StdHasher};
+///
+/// assert_eq!(
+///     readable_hash_text_reader::<StdHasher, _>(&b"a\r\nb\rc"[..]).unwrap(),
+///     readable_hash_text_reader::<StdHasher, _>(&b"a\nb\nc"[..]).unwrap()
+/// );
+/// ```
+#[cfg(feature = "std")]
+pub fn readable_hash_text_reader<H, R>(mut reader: R) -> io::Result<String>
+where
+    H: ReadableHasher,
+    R: Read,
+{
+    let mut hasher = H::default();
+    let mut buffer = [0u8; 8192];
+    let mut normalized = Vec::with_capacity(buffer.len());
+    let mut input_len = 0;
+    // A CR at the end of a chunk may be the first half of a CRLF
+    let mut pending_cr = false;
+    loop {
+        let bytes_read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(bytes_read) => bytes_read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        normalized.clear();
+        for byte in &buffer[..bytes_read] {
+            if pending_cr {
+                normalized.push(b'\n');
+                pending_cr = false;
+                if *byte == b'\n' {
+                    continue;
+                }
+            }
+            if *byte == b'\r' {
+                pending_cr = true;
+            } else {
+                normalized.push(*byte);
+            }
+        }
+        hasher.update(&normalized);
+        input_len += normalized.len();
+    }
+    if pending_cr {
+        hasher.update(b"\n");
+        input_len += 1;
+    }
+    Ok(english_word_from_hasher(hasher, input_len))
+}
+
+/// Generate english-like word hash of a file by memory-mapping it.
+///
+/// Avoids read syscalls for very large files. The result is identical to
+/// [`readable_hash_reader`] over the same file, and an empty file hashes to
+/// an empty string.
+///
+/// The file must not be modified by another process while it is mapped.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or mapped.
+#[cfg(feature = "memmap2")]
+pub fn readable_hash_mmap<H, P>(path: P) -> io::Result<String>
+where
+    H: ReadableHasher,
+    P: AsRef<std::path::Path>,
+{
+    let file = std::fs::File::open(path)?;
+    // Mapping a zero-length file fails on some platforms.
+    if file.metadata()?.len() == 0 {
+        return Ok(String::new());
+    }
+    // SAFETY: the mapping is read-only and dropped before returning. Callers
+    // are documented not to modify the file while it is being hashed.
+    let mapping = unsafe { memmap2::Mmap::map(&file)? };
+    Ok(english_word_hash::<H, _>(&mapping[..]))
+}
+
+/// Generate english-like word hash from at most `bytes` bytes of hasher
+/// output.
+///
+/// `bytes` is an explicit strength knob: the word carries at most
+/// `bytes * 8` bits of entropy. Once `bytes` reaches the number of bytes
+/// [`english_word_hash`] would read (8 for [`StdHasher`]), the result equals
+/// [`english_word_hash`]. A `bytes` of 0 returns an empty string.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{english_word_hash, readable_hash_strength, StdHasher};
+///
+/// assert_eq!(
+///     readable_hash_strength::<StdHasher, _>("hello", 8),
+///     english_word_hash::<StdHasher, _>("hello")
+/// );
+/// # }
+/// ```
+pub fn readable_hash_strength<H, T>(input: T, bytes: usize) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input_bytes = input.as_ref();
+    if input_bytes.is_empty() || bytes == 0 {
+        return String::new();
+    }
+    let input_len = input_bytes.len();
+
+    let reader = hashed_reader::<H>(input_bytes, input_len);
+    let mut limited_reader = LimitedByteReader::new(reader, Some(bytes));
+    english_word::generate_word_with_target_len(&mut limited_reader, input_len)
+}
+
+/// Generate a readable hash prefixed with a category name.
+///
+/// The first byte of hasher output picks one of `categories` (modulo their
+/// count), and the rest of the output generates the word, giving
+/// `"<category>-<word>"`. Use [`category_of`] to recover the category. With
+/// no categories, only the word is returned.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{categorized_readable_hash, category_of, StdHasher};
+///
+/// let categories = ["red", "green", "blue"];
+/// let tagged = categorized_readable_hash::<StdHasher, _>("hello", &categories);
+/// assert!(category_of(&tagged, &categories).is_some());
+/// # }
+/// ```
+pub fn categorized_readable_hash<H, T>(input: T, categories: &[&str]) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input_bytes = input.as_ref();
+    if input_bytes.is_empty() {
+        return String::new();
+    }
+    let input_len = input_bytes.len();
+
+    let mut reader = hashed_reader::<H>(input_bytes, input_len);
+    if categories.is_empty() {
+        return english_word::generate_word_with_target_len(&mut reader, input_len);
+    }
+
+    let mut category_byte = [0u8; 1];
+    reader.read(&mut category_byte);
+    let category = categories[category_byte[0] as usize % categories.len()];
+    let word = english_word::generate_word_with_target_len(&mut reader, input_len);
+    format!("{category}-{word}")
+}
+
+/// Recover the category of a [`categorized_readable_hash`] output.
+///
+/// Returns `None` if the string has no `-` or its prefix is not one of
+/// `categories`.
+pub fn category_of<'a>(hash: &str, categories: &[&'a str]) -> Option<&'a str> {
+    let (prefix, _) = hash.rsplit_once('-')?;
+    categories
+        .iter()
+        .find(|category| **category == prefix)
+        .copied()
+}
+
+/// Number of MinHash slots, and so words, in a [`readable_minhash`] sketch.
+const MINHASH_SLOTS: u32 = 8;
+
+/// Generate a readable MinHash sketch for near-duplicate detection.
+///
+/// The input is split into overlapping character shingles of `shingles`
+/// characters (at least 1). For each of 8 slots, every shingle is hashed
+/// with the slot number as a seed and the minimum value is kept. Each slot
+/// minimum is rendered as a word, and words are joined with `-`.
+///
+/// Similar inputs share most shingles, so most slots, and therefore most
+/// words, stay the same. Inputs shorter than one shingle are treated as a
+/// single shingle.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_minhash, StdHasher};
+///
+/// let sketch = readable_minhash::<StdHasher>("the quick brown fox", 3);
+/// assert_eq!(sketch.split('-').count(), 8);
+/// # }
+/// ```
+pub fn readable_minhash<H: ReadableHasher>(input: &str, shingles: usize) -> String {
+    let characters: Vec<char> = input.chars().collect();
+    if characters.is_empty() {
+        return String::new();
+    }
+    let shingle_len = shingles.clamp(1, characters.len());
+    let shingle_texts: Vec<String> = characters
+        .windows(shingle_len)
+        .map(|window| window.iter().collect())
+        .collect();
+
+    let mut words = Vec::with_capacity(MINHASH_SLOTS as usize);
+    for slot in 0..MINHASH_SLOTS {
+        let mut minimum = u64::MAX;
+        for shingle in &shingle_texts {
+            let mut hasher = H::default();
+            hasher.update(&slot.to_le_bytes());
+            hasher.update(shingle.as_bytes());
+            let mut value_bytes = [0u8; 8];
+            hasher.finalize().read(&mut value_bytes);
+            minimum = minimum.min(u64::from_be_bytes(value_bytes));
+        }
+        // Minimums are small, so their low-order bytes carry the entropy.
+        let minimum_bytes = minimum.to_le_bytes();
+        let mut reader = SliceReader::new(&minimum_bytes[..4]);
+        words.push(english_word::generate_word(&mut reader));
+    }
+    words.join("-")
+}
+
+/// Generate a readable hash from several inputs weighted by influence.
+///
+/// Each input is hashed on its own and contributes `weight` bytes of its
+/// hasher output to the entropy stream, in input order. When a finite
+/// hasher runs out, the input is hashed again with a little-endian `u32`
+/// block counter appended. The word is generated from the whole stream, so
+/// an input's share of the word grows with its weight: changing a
+/// low-weight input only changes the tail of the word. Inputs with weight 0
+/// are ignored. Since every byte becomes a token, keep total weights small.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_hash_weighted, StdHasher};
+///
+/// let word = readable_hash_weighted::<StdHasher, _>(&[("user-42", 4), ("nonce", 1)]);
+/// assert!(!word.is_empty());
+/// # }
+/// ```
+pub fn readable_hash_weighted<H, T>(inputs: &[(T, u32)]) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let mut entropy = Vec::new();
+    for (input, weight) in inputs {
+        entropy.extend(hashed_bytes::<H>(input.as_ref(), *weight as usize));
+    }
+
+    english_word::generate_word(&mut SliceReader::new(&entropy))
+}
+
+/// Generate a readable code: a word hash plus `digits` hashed digits and a
+/// trailing Luhn check digit, e.g. `"word-12345"`.
+///
+/// The check digit catches any single mistyped digit and most swaps of
+/// adjacent digits, which helps when codes are read aloud. Use
+/// [`validate_readable_code`] to check one. An empty input has an empty
+/// word hash, so its code is just `-` and the digits.
+///
+/// Each digit is a hashed byte modulo 10. Bytes of 250 and above are
+/// skipped and more are hashed instead, so every digit is equally likely.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_code, validate_readable_code, StdHasher};
+///
+/// let code = readable_code::<StdHasher, _>("ticket-1234", 4);
+/// assert!(validate_readable_code(&code));
+/// # }
+/// ```
+pub fn readable_code<H, T>(input: T, digits: usize) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input = input.as_ref();
+    // 256 is not a multiple of 10, so bytes of 250 and above are skipped
+    // rather than letting them favour digits 0 to 5
+    let mut byte_count = digits;
+    let mut code = loop {
+        let bytes = hashed_bytes::<H>(input, byte_count);
+        let code: Vec<u8> = bytes
+            .iter()
+            .filter(|byte| **byte < 250)
+            .map(|byte| byte % 10)
+            .take(digits)
+            .collect();
+        if code.len() == digits || bytes.len() < byte_count {
+            break code;
+        }
+        byte_count += digits - code.len();
+    };
+    code.push(luhn_check_digit(&code));
+
+    let mut result = english_word_hash::<H, _>(input);
+    result.push('-');
+    result.extend(code.iter().map(|digit| char::from(b'0' + digit)));
+    result
+}
+
+/// Check that a code produced by [`readable_code`] has a well-formed
+/// numeric part with a valid Luhn check digit.
+///
+/// Only the format and the check digit are verified, so this cannot tell
+/// whether the code belongs to a particular input. The word may be empty,
+/// as it is in the code of an empty input.
+///
+/// # Examples
+/// ```
+/// use readable_hash::validate_readable_code;
+///
+/// assert!(validate_readable_code("word-18"));
+/// assert!(validate_readable_code("-18"));
+/// assert!(!validate_readable_code("word-17"));
+/// assert!(!validate_readable_code("word"));
+/// ```
+pub fn validate_readable_code(code: &str) -> bool {
+    let Some((_, number)) = code.rsplit_once('-') else {
+        return false;
+    };
+    if number.is_empty() || !number.bytes().all(|byte| byte.is_ascii_digit()) {
+        return false;
+    }
+    let digits: Vec<u8> = number.bytes().map(|byte| byte - b'0').collect();
+    let (check_digit, payload) = digits.split_last().expect("number is not empty");
+    luhn_check_digit(payload) == *check_digit
+}
+
+/// Generate english-like word hash followed by `-` and a two-digit hex
+/// checksum, e.g. `"hired-78"`.
+///
+/// The checksum is a CRC-8 of the word rather than a digest byte, so
+/// [`verify_readable_hash_checksum`] can check it without the input. It
+/// catches any single changed letter and most other transcription errors.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_hash_with_checksum, verify_readable_hash_checksum, StdHasher};
+///
+/// let hash = readable_hash_with_checksum::<StdHasher, _>("hello");
+/// assert!(hash.starts_with("hired-"));
+/// assert!(verify_readable_hash_checksum(&hash));
+/// # }
+/// ```
+pub fn readable_hash_with_checksum<H, T>(input: T) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let word = english_word_hash::<H, _>(input);
+    let checksum = crc8(word.bytes());
+    format!("{word}-{checksum:02x}")
+}
+
+/// Check the checksum of a hash produced by
+/// [`readable_hash_with_checksum`].
+///
+/// The checksum may be in either case. The word may be empty, as it is
+/// for an empty input. Like [`validate_readable_code`], this cannot tell
+/// whether the hash belongs to a particular input.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_hash_with_checksum, verify_readable_hash_checksum, StdHasher};
+///
+/// let hash = readable_hash_with_checksum::<StdHasher, _>("hello");
+/// assert!(!verify_readable_hash_checksum(&hash.replacen('h', "n", 1)));
+/// assert!(!verify_readable_hash_checksum("hired"));
+/// # }
+/// ```
+pub fn verify_readable_hash_checksum(hash: &str) -> bool {
+    let Some((word, checksum)) = hash.rsplit_once('-') else {
+        return false;
+    };
+    if checksum.len() != 2 || !checksum.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return false;
+    }
+    u8::from_str_radix(checksum, 16).is_ok_and(|checksum| checksum == crc8(word.bytes()))
+}
+
+/// Generate a versioned readable code: `<version>-<word>-<checksum>`.
+///
+/// The version and the checksum are single syllables, and the word is
+/// [`english_word_hash`] of `input`. Words never contain `-`, so the three
+/// parts are always unambiguous. The checksum is a CRC-8 of the version and
+/// the word, so [`parse_readable_code_v2`] catches any single changed
+/// character of the word and most other typos.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{parse_readable_code_v2, readable_code_v2, StdHasher};
+///
+/// let code = readable_code_v2::<StdHasher, _>("ticket-1234", 2);
+/// assert_eq!(parse_readable_code_v2(&code), Some((2, true)));
+/// # }
+/// ```
+pub fn readable_code_v2<H, T>(input: T, version: u8) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let word = english_word_hash::<H, _>(input);
+    format!(
+        "{}-{word}-{}",
+        english_word::begin_syllable(version),
+        english_word::end_syllable(code_checksum(version, &word))
+    )
+}
+
+/// Parse a code produced by [`readable_code_v2`].
+///
+/// Returns the version and whether the checksum matches, or `None` if the
+/// code does not have three `-`-separated parts or its version or checksum
+/// is not a known syllable. Like [`validate_readable_code`], this cannot
+/// tell whether the code belongs to a particular input.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{parse_readable_code_v2, readable_code_v2, StdHasher};
+///
+/// let code = readable_code_v2::<StdHasher, _>("ticket-1234", 2);
+/// let corrupted = code.replacen('-', "-x", 1);
+/// assert_eq!(parse_readable_code_v2(&corrupted), Some((2, false)));
+/// assert_eq!(parse_readable_code_v2("not a code"), None);
+/// # }
+/// ```
+pub fn parse_readable_code_v2(code: &str) -> Option<(u8, bool)> {
+    let mut parts = code.split('-');
+    let (Some(version), Some(word), Some(checksum), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+    let version = english_word::parse_begin_syllable(version)?;
+    let checksum = english_word::parse_end_syllable(checksum)?;
+    Some((version, code_checksum(version, word) == checksum))
+}
+
+/// CRC-8 (polynomial `0x07`) of `version` followed by `word`.
+fn code_checksum(version: u8, word: &str) -> u8 {
+    crc8(core::iter::once(version).chain(word.bytes()))
+}
+
+/// CRC-8 (polynomial `0x07`) of `bytes`.
+fn crc8(bytes: impl Iterator<Item = u8>) -> u8 {
+    let mut crc: u8 = 0;
+    for byte in bytes {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Luhn check digit for the decimal digits in `payload`.
+fn luhn_check_digit(payload: &[u8]) -> u8 {
+    let sum: u32 = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, digit)| {
+            let digit = u32::from(*digit);
+            if i % 2 == 0 {
+                let doubled = digit * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// Generate an uppercase, acronym-style readable hash of exactly `len`
+/// characters.
+///
+/// The word is generated with `len` as its minimum target length and then
+/// cut down to `len` characters. If the hasher runs out of entropy before
+/// the word reaches `len`, the shorter word is returned as is.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{readable_acronym, StdHasher};
+///
+/// let acronym = readable_acronym::<StdHasher, _>("hello", 4);
+/// assert_eq!(acronym.len(), 4);
+/// assert!(acronym.chars().all(|character| character.is_ascii_uppercase()));
+/// # }
+/// ```
+pub fn readable_acronym<H, T>(input: T, len: usize) -> String
+where
+    H: ReadableHasher,
+    T: AsRef<[u8]>,
+{
+    let input_bytes = input.as_ref();
+    if input_bytes.is_empty() || len == 0 {
+        return String::new();
+    }
+
+    let mut limited_reader = hashed_reader::<H>(input_bytes, len);
+    let mut word = english_word::generate_word_with_target_len(&mut limited_reader, len);
+    word.truncate(len);
+    word.make_ascii_uppercase();
+    word
+}
+
+/// Generate english-like word hash of a directory tree.
+///
+/// Every regular file below `path` contributes the raw bytes of its
+/// relative path (with `/` separators) and its content, each followed by
+/// its length as a little-endian `u64`. Files are visited in sorted path order, so identical
+/// trees hash the same regardless of filesystem enumeration order. Empty
+/// directories do not contribute, and symbolic links are not followed.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be walked or a file cannot be
+/// read.
+#[cfg(feature = "std")]
+pub fn readable_hash_dir<H, P>(path: P) -> io::Result<String>
+where
+    H: ReadableHasher,
+    P: AsRef<std::path::Path>,
+{
+    let root = path.as_ref();
+    let mut files = Vec::new();
+    collect_files(root, root, &mut files)?;
+    files.sort();
+
+    let mut hasher = H::default();
+    let mut input_len = 0;
+    let mut buffer = [0u8; 8192];
+    for (relative_path, file_path) in files {
+        hasher.update(&relative_path);
+        hasher.update(&(relative_path.len() as u64).to_le_bytes());
+
+        let mut file = std::fs::File::open(file_path)?;
+        let mut content_len: u64 = 0;
+        loop {
+            let bytes_read = match file.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(bytes_read) => bytes_read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            hasher.update(&buffer[..bytes_read]);
+            content_len += bytes_read as u64;
+        }
+        hasher.update(&content_len.to_le_bytes());
+
+        input_len += relative_path.len() + content_len as usize + 16;
+    }
+
+    Ok(english_word_from_hasher(hasher, input_len))
+}
+
+/// Recursively collect the regular files below `dir`, each with its path
+/// relative to `root` and joined with `/`.
+///
+/// Names are kept as raw bytes, so names that are not valid UTF-8 stay
+/// distinct instead of collapsing into the same replacement characters.
+#[cfg(feature = "std")]
+fn collect_files(
+    root: &std::path::Path,
+    dir: &std::path::Path,
+    files: &mut Vec<(Vec<u8>, std::path::PathBuf)>,
+) -> io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let entry_path = entry.path();
+        if file_type.is_dir() {
+            collect_files(root, &entry_path, files)?;
+        } else if file_type.is_file() {
+            let relative_path = entry_path
+                .strip_prefix(root)
+                .map_err(io::Error::other)?
+                .components()
+                .map(|component| component.as_os_str().as_encoded_bytes())
+                .collect::<Vec<_>>()
+                .join(&b'/');
+            files.push((relative_path, entry_path));
+        }
+    }
+    Ok(())
+}
+
+/// Finalize `hasher` and generate a word for an input of `input_len` bytes.
+fn english_word_from_hasher<H: ReadableHasher>(hasher: H, input_len: usize) -> String {
+    if input_len == 0 {
+        return String::new();
+    }
+    let mut limited_reader = limit_reader(hasher.finalize(), input_len);
+    english_word::generate_word_with_target_len(&mut limited_reader, input_len)
+}
+
+/// Hash `input` into exactly `len` bytes, if the hasher can produce them.
+///
+/// Finite hashers are rehashed with a little-endian `u32` block counter
+/// appended to `input` until enough bytes are collected.
+fn hashed_bytes<H: ReadableHasher>(input: &[u8], len: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; len];
+    let mut filled = 0;
+    let mut block: u32 = 0;
+    while filled < len {
+        let mut hasher = H::default();
+        hasher.update(input);
+        if block > 0 {
+            hasher.update(&block.to_le_bytes());
+        }
+        let mut reader = hasher.finalize();
+        let bytes_read = reader.read(&mut bytes[filled..]);
+        if bytes_read == 0 {
+            break;
+        }
+        filled += bytes_read;
+        block += 1;
+    }
+    bytes.truncate(filled);
+    bytes
+}
+
+/// Hash `input` and return its output stream.
+///
+/// See [`limit_reader`] for how infinite outputs are bounded.
+fn hashed_reader<H: ReadableHasher>(
+    input: &[u8],
+    target_len: usize,
+) -> LimitedByteReader<H::Reader> {
+    let mut hasher = H::default();
+    hasher.update(input);
+    limit_reader(hasher.finalize(), target_len)
+}
+
+/// Bound a hasher output stream.
+///
+/// Readers of known length are used in full. Readers of unknown length,
+/// infinite or not, are limited to `target_len` bytes (minimum 8) so the
+/// generated word stays proportional to the requested length.
+fn limit_reader<R: ByteReader>(reader: R, target_len: usize) -> LimitedByteReader<R> {
+    // For readers of unknown length, wrap with a length limiter
+    let bytes_limit = match reader.remaining() {
+        Some(_) => None,                 // Known length: use all
+        None => Some(target_len.max(8)), // Unknown: limit to target length
+    };
+
+    LimitedByteReader::new(reader, bytes_limit)
+}
+
+/// An endless sequence of unique readable names.
+///
+/// Yields [`english_word_hash`] of an incrementing counter (`"0"`, `"1"`,
+/// ...), skipping names that were already produced. The counter is a `u128`,
+/// so its decimal form matches a `u64` counter and it never wraps in
+/// practice. Every produced name is remembered, so memory grows with the
+/// number of names taken.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use readable_hash::{ReadableNameSequence, StdHasher};
+///
+/// let names: Vec<String> = ReadableNameSequence::<StdHasher>::new().take(3).collect();
+/// assert_eq!(names.len(), 3);
+/// # }
+/// ```
+pub struct ReadableNameSequence<H: ReadableHasher> {
+    counter: Option<u128>,
+    seen: BTreeSet<String>,
+    hasher: PhantomData<H>,
+}
+
+impl<H: ReadableHasher> ReadableNameSequence<H> {
+    pub fn new() -> Self {
+        Self {
+            counter: Some(0),
+            seen: BTreeSet::new(),
+            hasher: PhantomData,
+        }
+    }
+}
+
+impl<H: ReadableHasher> Default for ReadableNameSequence<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: ReadableHasher> Iterator for ReadableNameSequence<H> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        loop {
+            let counter = self.counter?;
+            self.counter = counter.checked_add(1);
+            let name = english_word_hash::<H, _>(counter.to_string());
+            if self.seen.insert(name.clone()) {
+                return Some(name);
+            }
+        }
+    }
 }
 
 /// A ByteReader wrapper that limits the number of bytes read.
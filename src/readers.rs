@@ -0,0 +1,319 @@
+//! [`ByteReader`] adapters for other byte sources.
+
+use crate::ByteReader;
+use alloc::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+/// A [`ByteReader`] that pulls bytes from an iterator.
+///
+/// Custom entropy sources, such as a CSPRNG, can drive word generation
+/// through this. The remaining length is known only when the iterator's
+/// [`size_hint`](Iterator::size_hint) is exact.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, IterReader, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF];
+/// assert_eq!(
+///     english_word::generate_word(&mut IterReader::new(entropy.into_iter())),
+///     english_word::generate_word(&mut SliceReader::new(&entropy))
+/// );
+/// ```
+pub struct IterReader<I: Iterator<Item = u8>> {
+    bytes: I,
+}
+
+impl<I: Iterator<Item = u8>> IterReader<I> {
+    /// Wrap `bytes`.
+    pub const fn new(bytes: I) -> Self {
+        Self { bytes }
+    }
+}
+
+impl<I: Iterator<Item = u8>> ByteReader for IterReader<I> {
+    fn read(&mut self, dest: &mut [u8]) -> usize {
+        dest.iter_mut()
+            .zip(&mut self.bytes)
+            .map(|(slot, byte)| *slot = byte)
+            .count()
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        match self.bytes.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower),
+            _ => None,
+        }
+    }
+}
+
+/// A [`ByteReader`] that reads `first` to the end, then continues with
+/// `second`.
+///
+/// A single [`read`](ByteReader::read) may span both readers, so a
+/// domain-separation prefix can be placed in front of digest bytes without
+/// changing how they are consumed. A short read from `first` is taken to
+/// mean it is exhausted.
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, ChainReader, SliceReader};
+///
+/// let mut chained = ChainReader::new(SliceReader::new(&[0xDE, 0xAD]), SliceReader::new(&[0xBE, 0xEF]));
+/// assert_eq!(
+///     english_word::generate_word(&mut chained),
+///     english_word::generate_word(&mut SliceReader::new(&[0xDE, 0xAD, 0xBE, 0xEF]))
+/// );
+/// ```
+pub struct ChainReader<A: ByteReader, B: ByteReader> {
+    first: A,
+    second: B,
+    first_done: bool,
+}
+
+impl<A: ByteReader, B: ByteReader> ChainReader<A, B> {
+    /// Read `first`, then `second`.
+    pub const fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            first_done: false,
+        }
+    }
+
+    /// Unwrap the two readers.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: ByteReader, B: ByteReader> ByteReader for ChainReader<A, B> {
+    fn read(&mut self, dest: &mut [u8]) -> usize {
+        let mut filled = 0;
+        if !self.first_done {
+            filled = self.first.read(dest);
+            if filled == dest.len() {
+                return filled;
+            }
+            self.first_done = true;
+        }
+        filled + self.second.read(&mut dest[filled..])
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        let second = self.second.remaining()?;
+        if self.first_done {
+            return Some(second);
+        }
+        self.first.remaining()?.checked_add(second)
+    }
+}
+
+/// A [`ByteReader`] that drains bytes from the front of a [`VecDeque`].
+///
+/// Read bytes are removed from the deque, so a producer can keep appending
+/// entropy while words consume it. Generators read one byte per token and
+/// never read past the end token, so after a word the deque holds exactly
+/// the bytes it did not use.
+///
+/// # Examples
+/// ```
+/// use std::collections::VecDeque;
+/// use readable_hash::{english_word, DequeReader};
+///
+/// let mut entropy = VecDeque::from([0xAE, 0xF4, 0xFF, 0xDE, 0xAD]);
+/// let word = english_word::generate_word(&mut DequeReader::new(&mut entropy));
+/// assert_eq!(word, "elatior");
+/// assert_eq!(entropy, [0xDE, 0xAD]);
+/// ```
+pub struct DequeReader<'a> {
+    bytes: &'a mut VecDeque<u8>,
+}
+
+impl<'a> DequeReader<'a> {
+    /// Drain `bytes` from the front.
+    pub const fn new(bytes: &'a mut VecDeque<u8>) -> Self {
+        Self { bytes }
+    }
+}
+
+impl ByteReader for DequeReader<'_> {
+    fn read(&mut self, dest: &mut [u8]) -> usize {
+        let count = dest.len().min(self.bytes.len());
+        for (slot, byte) in dest.iter_mut().zip(self.bytes.drain(..count)) {
+            *slot = byte;
+        }
+        count
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        Some(self.bytes.len())
+    }
+}
+
+/// A [`ByteReader`] that counts the bytes another reader yields.
+///
+/// Works with any generator, unlike
+/// [`english_word::generate_word_counted`], and leaves the wrapped reader
+/// untouched.
+///
+/// [`english_word::generate_word_counted`]: crate::english_word::generate_word_counted
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, CountingReader, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE, 0xBA, 0xBE, 0x01];
+/// let mut reader = CountingReader::new(SliceReader::new(&entropy));
+/// let passphrase = english_word::generate_passphrase(&mut reader, 2, "-");
+/// assert_eq!(reader.bytes_read(), 8);
+/// ```
+pub struct CountingReader<R: ByteReader> {
+    reader: R,
+    bytes_read: usize,
+}
+
+impl<R: ByteReader> CountingReader<R> {
+    /// Count the bytes read from `reader`.
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            bytes_read: 0,
+        }
+    }
+
+    /// Bytes the wrapped reader has yielded so far.
+    pub const fn bytes_read(&self) -> usize {
+        self.bytes_read
+    }
+
+    /// Unwrap the reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: ByteReader> ByteReader for CountingReader<R> {
+    fn read(&mut self, dest: &mut [u8]) -> usize {
+        let bytes_read = self.reader.read(dest);
+        self.bytes_read += bytes_read;
+        bytes_read
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        self.reader.remaining()
+    }
+}
+
+/// A [`ByteReader`] that cycles through a slice forever.
+///
+/// A few seed bytes can drive output of any length, but generators that
+/// read until the reader is exhausted, such as
+/// [`english_word::generate_word`], never finish with it. Use bounded ones
+/// like [`english_word::generate_word_with_max_len`] or
+/// [`english_word::generate_passphrase`]. An empty slice has nothing to
+/// repeat and reads as exhausted.
+///
+/// [`english_word::generate_word`]: crate::english_word::generate_word
+/// [`english_word::generate_word_with_max_len`]: crate::english_word::generate_word_with_max_len
+/// [`english_word::generate_passphrase`]: crate::english_word::generate_passphrase
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, RepeatReader};
+///
+/// let passphrase = english_word::generate_passphrase(&mut RepeatReader::new(&[0xDE, 0xAD]), 6, "-");
+/// assert_eq!(passphrase.split('-').count(), 6);
+/// ```
+pub struct RepeatReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> RepeatReader<'a> {
+    /// Repeat `data`.
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+}
+
+impl ByteReader for RepeatReader<'_> {
+    fn read(&mut self, dest: &mut [u8]) -> usize {
+        if self.data.is_empty() {
+            return 0;
+        }
+        for slot in dest.iter_mut() {
+            *slot = self.data[self.position];
+            self.position = (self.position + 1) % self.data.len();
+        }
+        dest.len()
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        if self.data.is_empty() { Some(0) } else { None }
+    }
+}
+
+/// A [`ByteReader`] that pulls bytes from an [`io::Read`] on demand.
+///
+/// Files and network streams can feed [`english_word::generate_word`]
+/// without being loaded into memory first. An I/O error other than
+/// [`io::ErrorKind::Interrupted`] ends the stream like EOF, so word
+/// generation still terminates; the error itself is dropped.
+///
+/// [`english_word::generate_word`]: crate::english_word::generate_word
+///
+/// # Examples
+/// ```
+/// use readable_hash::{english_word, ReadAdapter, SliceReader};
+///
+/// let entropy = [0xDE, 0xAD, 0xBE, 0xEF];
+/// assert_eq!(
+///     english_word::generate_word(&mut ReadAdapter::new(&entropy[..])),
+///     english_word::generate_word(&mut SliceReader::new(&entropy))
+/// );
+/// ```
+#[cfg(feature = "std")]
+pub struct ReadAdapter<R: Read> {
+    reader: R,
+    exhausted: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> ReadAdapter<R> {
+    /// Wrap `reader`.
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            exhausted: false,
+        }
+    }
+
+    /// Unwrap the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> ByteReader for ReadAdapter<R> {
+    fn read(&mut self, dest: &mut [u8]) -> usize {
+        let mut filled = 0;
+        while filled < dest.len() && !self.exhausted {
+            match self.reader.read(&mut dest[filled..]) {
+                Ok(0) => self.exhausted = true,
+                Ok(bytes_read) => filled += bytes_read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => self.exhausted = true,
+            }
+        }
+        filled
+    }
+
+    /// `Some(0)` once the stream has ended, otherwise `None`: the length
+    /// of a stream is not known until it has been read to the end.
+    fn remaining(&self) -> Option<usize> {
+        self.exhausted.then_some(0)
+    }
+}
@@ -0,0 +1,15 @@
+//! JavaScript bindings built with `wasm-bindgen`.
+//!
+//! Build the package with `wasm-pack build --target web -- --features wasm`
+//! and run the tests with `wasm-pack test --node -- --features wasm`.
+
+use crate::StdHasher;
+use alloc::string::String;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// [`english_word_hash`](crate::english_word_hash) of `input` with the
+/// default [`StdHasher`].
+#[wasm_bindgen]
+pub fn english_word_hash(input: &str) -> String {
+    crate::english_word_hash::<StdHasher, _>(input)
+}
@@ -1,8 +1,44 @@
 use cucumber::{World as _, given, then, when};
 use futures::executor::block_on;
+#[cfg(feature = "serde")]
+use readable_hash::ReadableHash;
+#[cfg(feature = "sha512")]
+use readable_hash::Sha512Hasher;
 #[cfg(feature = "shake256")]
 use readable_hash::Shake256Hasher;
-use readable_hash::{SliceReader, StdHasher, english_word, english_word_hash};
+use readable_hash::bits::BitReader;
+use readable_hash::english_word::{EndReason, SeparatorPolicy};
+#[cfg(feature = "bincode")]
+use readable_hash::readable_hash_bincode;
+#[cfg(feature = "hmac")]
+use readable_hash::readable_hash_hmac;
+#[cfg(feature = "json")]
+use readable_hash::readable_hash_json_str;
+#[cfg(feature = "memmap2")]
+use readable_hash::readable_hash_mmap;
+#[cfg(feature = "blake3")]
+use readable_hash::{Blake3Hasher, Blake3Reader};
+use readable_hash::{
+    ByteReader, ChainReader, CountingReader, DequeReader, IterReader, ReadableHasher,
+    ReadableNameSequence, RepeatReader, SliceReader, StdHasher, categorized_readable_hash,
+    category_of, english_word, english_word_hash, english_word_hash_fixed, english_word_hash_words,
+    parse_readable_code_v2, readable_acronym, readable_code, readable_code_v2,
+    readable_hash_batch_write, readable_hash_be, readable_hash_capitalized, readable_hash_dir,
+    readable_hash_explain, readable_hash_le, readable_hash_map, readable_hash_nonced,
+    readable_hash_reader, readable_hash_reader_counted, readable_hash_reader_limited,
+    readable_hash_rounds, readable_hash_salted, readable_hash_strength, readable_hash_tagged,
+    readable_hash_text_reader, readable_hash_trimmed_input, readable_hash_uppercase,
+    readable_hash_weighted, readable_hash_with_checksum, readable_hash_with_separator,
+    readable_hash_with_table, readable_minhash, sortable_readable_key, try_readable_hash,
+    validate_readable_code, verify_readable_hash_checksum,
+};
+#[cfg(feature = "std")]
+use readable_hash::{HashWriter, LimitedReadError, ReadAdapter};
+#[cfg(feature = "cache")]
+use readable_hash::{readable_hash_cache_len, readable_hash_cached};
+use std::collections::VecDeque;
+use std::io::Write as _;
+use tempfile::{NamedTempFile, TempDir};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 enum HasherType {
@@ -10,6 +46,37 @@ enum HasherType {
     Std,
     #[cfg(feature = "shake256")]
     Shake256,
+    #[cfg(feature = "sha512")]
+    Sha512,
+    #[cfg(feature = "blake3")]
+    Blake3,
+}
+
+/// Runs `$body` with `$hasher` bound to the hasher type selected in the world.
+macro_rules! with_hasher {
+    ($hasher_type:expr, |$hasher:ident| $body:expr) => {
+        match $hasher_type {
+            HasherType::Std => {
+                type $hasher = StdHasher;
+                $body
+            }
+            #[cfg(feature = "shake256")]
+            HasherType::Shake256 => {
+                type $hasher = Shake256Hasher;
+                $body
+            }
+            #[cfg(feature = "sha512")]
+            HasherType::Sha512 => {
+                type $hasher = Sha512Hasher;
+                $body
+            }
+            #[cfg(feature = "blake3")]
+            HasherType::Blake3 => {
+                type $hasher = Blake3Hasher;
+                $body
+            }
+        }
+    };
 }
 
 #[derive(Debug, Default, cucumber::World)]
@@ -18,6 +85,13 @@ struct HashWorld {
     output: String,
     entropy: Vec<u8>,
     hasher_type: HasherType,
+    file: Option<NamedTempFile>,
+    outputs: Vec<String>,
+    directories: Vec<TempDir>,
+    end_reason: Option<EndReason>,
+    byte_count: Option<usize>,
+    cache_len: Option<(usize, usize)>,
+    padded: Option<bool>,
 }
 
 #[given(expr = "the input {string}")]
@@ -25,6 +99,11 @@ fn set_input(world: &mut HashWorld, input: String) {
     world.input = input;
 }
 
+#[given(expr = "the input {string} followed by a newline")]
+fn set_input_with_newline(world: &mut HashWorld, input: String) {
+    world.input = format!("{input}\n");
+}
+
 #[given("using the std hasher")]
 fn use_std_hasher(world: &mut HashWorld) {
     world.hasher_type = HasherType::Std;
@@ -36,13 +115,893 @@ fn use_shake256_hasher(world: &mut HashWorld) {
     world.hasher_type = HasherType::Shake256;
 }
 
+#[cfg(feature = "sha512")]
+#[given("using the sha512 hasher")]
+fn use_sha512_hasher(world: &mut HashWorld) {
+    world.hasher_type = HasherType::Sha512;
+}
+
+#[cfg(feature = "blake3")]
+#[given("using the blake3 hasher")]
+fn use_blake3_hasher(world: &mut HashWorld) {
+    world.hasher_type = HasherType::Blake3;
+}
+
 #[when("the english word hash is generated")]
 fn generate_english_word_hash(world: &mut HashWorld) {
-    world.output = match world.hasher_type {
-        HasherType::Std => english_word_hash::<StdHasher, _>(&world.input),
-        #[cfg(feature = "shake256")]
-        HasherType::Shake256 => english_word_hash::<Shake256Hasher, _>(&world.input),
+    world.output = with_hasher!(world.hasher_type, |H| english_word_hash::<H, _>(
+        &world.input
+    ));
+}
+
+#[when("the english word hash of the trimmed input is generated")]
+fn generate_trimmed_english_word_hash(world: &mut HashWorld) {
+    world.output = with_hasher!(world.hasher_type, |H| readable_hash_trimmed_input::<H, _>(
+        &world.input
+    ));
+}
+
+#[when(expr = "the english word hash is generated with a limit of {int} bytes")]
+fn generate_limited_english_word_hash(world: &mut HashWorld, max_len: usize) {
+    let result = with_hasher!(world.hasher_type, |H| try_readable_hash::<H, _>(
+        &world.input,
+        max_len
+    ));
+    world.output = match result {
+        Ok(word) => word,
+        Err(error) => format!("error: {error}"),
+    };
+}
+
+#[cfg(feature = "json")]
+#[when(expr = "the JSON documents {string} and {string} are hashed")]
+fn hash_json_documents(world: &mut HashWorld, first: String, second: String) {
+    world.outputs = [first, second]
+        .iter()
+        .map(|json| {
+            let result = with_hasher!(world.hasher_type, |H| readable_hash_json_str::<H>(json));
+            result.unwrap_or_else(|error| format!("error: {error}"))
+        })
+        .collect();
+}
+
+#[cfg(feature = "cache")]
+#[when(expr = "the cached hash of the input is generated {int} times")]
+fn generate_cached_hashes(world: &mut HashWorld, times: usize) {
+    let cache_len_before = readable_hash_cache_len();
+    world.outputs = (0..times)
+        .map(|_| {
+            with_hasher!(world.hasher_type, |H| readable_hash_cached::<H, _>(
+                &world.input
+            ))
+        })
+        .collect();
+    world.cache_len = Some((cache_len_before, readable_hash_cache_len()));
+}
+
+#[then(expr = "the cache should have grown by {int}")]
+fn check_cache_growth(world: &mut HashWorld, expected: usize) {
+    let (cache_len_before, cache_len_after) =
+        world.cache_len.expect("No cached hash was generated");
+    assert_eq!(cache_len_after - cache_len_before, expected);
+}
+
+#[cfg(feature = "bincode")]
+#[when(expr = "the record {string} is built in two ways and hashed with bincode")]
+fn hash_bincode_records(world: &mut HashWorld, record: String) {
+    use std::collections::BTreeMap;
+
+    // Record format: name;number;comma-separated u16 values;comma-separated flags
+    let fields: Vec<&str> = record.split(';').collect();
+    let [name, number, values, flags] = fields[..] else {
+        panic!("Expected four fields in {record}");
+    };
+    let number: u32 = number.parse().expect("Invalid number");
+    let values: Vec<u16> = values
+        .split(',')
+        .map(|value| value.parse().expect("Invalid value"))
+        .collect();
+    let flags: Vec<&str> = flags.split(',').collect();
+
+    let first = (
+        name.to_string(),
+        number,
+        values.clone(),
+        flags
+            .iter()
+            .map(|flag| (flag.to_string(), true))
+            .collect::<BTreeMap<_, _>>(),
+    );
+    let mut reversed_values = Vec::new();
+    for value in values.iter().rev() {
+        reversed_values.insert(0, *value);
+    }
+    let mut reversed_flags = BTreeMap::new();
+    for flag in flags.iter().rev() {
+        reversed_flags.insert(flag.to_string(), true);
+    }
+    let second = (String::from(name), number, reversed_values, reversed_flags);
+
+    world.outputs = [first, second]
+        .iter()
+        .map(|value| {
+            with_hasher!(world.hasher_type, |H| readable_hash_bincode::<H, _>(value))
+                .expect("Failed to encode value")
+        })
+        .collect();
+}
+
+#[when(expr = "the english word hash is generated with {string} rounds")]
+fn generate_english_word_hashes_with_rounds(world: &mut HashWorld, rounds: String) {
+    world.outputs = rounds
+        .split(',')
+        .map(|rounds| {
+            let rounds = rounds.parse().expect("Invalid round count");
+            with_hasher!(world.hasher_type, |H| readable_hash_rounds::<H, _>(
+                &world.input,
+                rounds
+            ))
+        })
+        .collect();
+}
+
+#[when(expr = "the english word hash is generated with nonces {string}")]
+fn generate_english_word_hashes_with_nonces(world: &mut HashWorld, nonces: String) {
+    world.outputs = nonces
+        .split(',')
+        .map(|nonce| {
+            let nonce = nonce.parse().expect("Invalid nonce");
+            with_hasher!(world.hasher_type, |H| readable_hash_nonced::<H, _>(
+                &world.input,
+                nonce
+            ))
+        })
+        .collect();
+}
+
+#[when(expr = "a word hash phrase of {int} words is generated with separator {string}")]
+fn generate_hash_phrase(world: &mut HashWorld, word_count: usize, separator: String) {
+    world.output = with_hasher!(world.hasher_type, |H| english_word_hash_words::<H, _>(
+        &world.input,
+        word_count,
+        &separator
+    ));
+}
+
+/// A hasher whose digest is its input, to test the extension point.
+#[derive(Default)]
+struct IdentityHasher {
+    bytes: Vec<u8>,
+}
+
+impl ReadableHasher for IdentityHasher {
+    type Reader = IterReader<std::vec::IntoIter<u8>>;
+
+    fn update(&mut self, data: &[u8]) {
+        self.bytes.extend_from_slice(data);
+    }
+
+    fn finalize(self) -> Self::Reader {
+        IterReader::new(self.bytes.into_iter())
+    }
+}
+
+#[when(
+    expr = "a readable code with {int} digits of the entropy is generated with an identity hasher"
+)]
+fn generate_identity_readable_code(world: &mut HashWorld, digits: usize) {
+    world.output = readable_code::<IdentityHasher, _>(&world.entropy, digits);
+}
+
+#[when("the english word hash of the entropy is generated with an identity hasher")]
+fn generate_identity_english_word_hash(world: &mut HashWorld) {
+    world.output = english_word_hash::<IdentityHasher, _>(&world.entropy);
+}
+
+#[when(expr = "the english word hash is generated in {word} case")]
+fn generate_cased_english_word_hash(world: &mut HashWorld, case: String) {
+    world.output = with_hasher!(world.hasher_type, |H| cased_english_word_hash::<H>(
+        &world.input,
+        &case
+    ));
+}
+
+fn cased_english_word_hash<H: ReadableHasher>(input: &str, case: &str) -> String {
+    match case {
+        "upper" => readable_hash_uppercase::<H, _>(input),
+        "title" => readable_hash_capitalized::<H, _>(input),
+        _ => panic!("Unknown case: {case}"),
+    }
+}
+
+#[when(expr = "the english word hash of exactly {int} characters is generated")]
+fn generate_fixed_english_word_hash(world: &mut HashWorld, exact_len: usize) {
+    world.output = with_hasher!(world.hasher_type, |H| english_word_hash_fixed::<H, _>(
+        &world.input,
+        exact_len
+    ));
+}
+
+#[when(expr = "the english word hash is generated with salt {string}")]
+fn generate_salted_english_word_hash(world: &mut HashWorld, salt: String) {
+    let salt = salt.as_bytes();
+    world.output = with_hasher!(world.hasher_type, |H| readable_hash_salted::<H, _>(
+        &world.input,
+        salt
+    ));
+}
+
+#[cfg(feature = "hmac")]
+#[when(expr = "the input is hashed with the hmac key {string}")]
+fn hash_input_with_hmac(world: &mut HashWorld, key: String) {
+    world.output = readable_hash_hmac(key.as_bytes(), world.input.as_bytes());
+}
+
+#[then(expr = "all results should start with {string}")]
+fn check_results_prefix(world: &mut HashWorld, prefix: String) {
+    for output in &world.outputs {
+        assert!(
+            output.starts_with(&prefix),
+            "Result '{}' does not start with '{}'",
+            output,
+            prefix
+        );
+    }
+}
+
+#[then(expr = "the results should be {string}")]
+fn check_results(world: &mut HashWorld, expected: String) {
+    assert_eq!(world.outputs.join(","), expected);
+}
+
+#[when(expr = "the english word hash is generated with strength {int}")]
+fn generate_english_word_hash_with_strength(world: &mut HashWorld, bytes: usize) {
+    world.output = with_hasher!(world.hasher_type, |H| readable_hash_strength::<H, _>(
+        &world.input,
+        bytes
+    ));
+}
+
+#[when(expr = "the categorized hash is generated with categories {string}")]
+fn generate_categorized_hash(world: &mut HashWorld, categories: String) {
+    let categories: Vec<&str> = categories.split(',').collect();
+    world.output = with_hasher!(world.hasher_type, |H| categorized_readable_hash::<H, _>(
+        &world.input,
+        &categories
+    ));
+}
+
+#[then(expr = "the category should be recovered as {string} from {string}")]
+fn check_category(world: &mut HashWorld, expected: String, categories: String) {
+    let categories: Vec<&str> = categories.split(',').collect();
+    assert_eq!(
+        category_of(&world.output, &categories),
+        Some(expected.as_str()),
+        "Output: '{}'",
+        world.output
+    );
+}
+
+#[then(expr = "the category should not be recovered from {string}")]
+fn check_no_category(world: &mut HashWorld, categories: String) {
+    let categories: Vec<&str> = categories.split(',').collect();
+    assert_eq!(category_of(&world.output, &categories), None);
+}
+
+#[when(
+    expr = "the minhash sketches of {string} and {string} are generated with shingle size {int}"
+)]
+fn generate_minhash_sketches(world: &mut HashWorld, first: String, second: String, size: usize) {
+    world.outputs = [first, second]
+        .iter()
+        .map(|input| with_hasher!(world.hasher_type, |H| readable_minhash::<H>(input, size)))
+        .collect();
+}
+
+#[then(expr = "at least {int} of {int} sketch words should match")]
+fn check_sketch_overlap(world: &mut HashWorld, min_matching: usize, word_count: usize) {
+    let first: Vec<&str> = world.outputs[0].split('-').collect();
+    let second: Vec<&str> = world.outputs[1].split('-').collect();
+    assert_eq!(first.len(), word_count);
+    assert_eq!(second.len(), word_count);
+    let matching = first
+        .iter()
+        .zip(&second)
+        .filter(|(left, right)| left == right)
+        .count();
+    assert!(
+        matching >= min_matching,
+        "Only {} words match: {:?}",
+        matching,
+        world.outputs
+    );
+}
+
+#[when(expr = "the readable acronym of length {int} is generated")]
+fn generate_readable_acronym(world: &mut HashWorld, len: usize) {
+    world.output = with_hasher!(world.hasher_type, |H| readable_acronym::<H, _>(
+        &world.input,
+        len
+    ));
+}
+
+#[given(expr = "a file containing {string}")]
+fn create_file(world: &mut HashWorld, contents: String) {
+    let mut file = NamedTempFile::new().expect("Failed to create temp file");
+    file.write_all(contents.as_bytes())
+        .expect("Failed to write temp file");
+    world.file = Some(file);
+}
+
+#[when("the file is hashed through a reader")]
+fn hash_file_through_reader(world: &mut HashWorld) {
+    let file = world.file.as_ref().expect("No file was created");
+    let reader = std::fs::File::open(file.path()).expect("Failed to open temp file");
+    world.output = with_hasher!(world.hasher_type, |H| readable_hash_reader::<H, _>(reader))
+        .expect("Failed to hash file");
+}
+
+/// Turn `\\r` and `\\n` escapes from feature files into line endings.
+fn unescape_line_endings(text: &str) -> String {
+    text.replace("\\r", "\r").replace("\\n", "\n")
+}
+
+#[then(expr = "the first result should match the english word hash of the escaped text {string}")]
+fn check_first_matches_escaped_hash(world: &mut HashWorld, text: String) {
+    let text = unescape_line_endings(&text);
+    let expected = with_hasher!(world.hasher_type, |H| english_word_hash::<H, _>(&text));
+    assert_eq!(world.outputs.first(), Some(&expected));
+}
+
+#[when(expr = "the texts {string} and {string} are hashed after {int} padding bytes")]
+fn hash_texts_ignoring_line_endings(
+    world: &mut HashWorld,
+    first: String,
+    second: String,
+    padding: usize,
+) {
+    world.outputs = [first, second]
+        .iter()
+        .map(|text| {
+            let mut bytes = vec![b'x'; padding];
+            bytes.extend(unescape_line_endings(text).bytes());
+            let result = with_hasher!(world.hasher_type, |H| readable_hash_text_reader::<H, _>(
+                &bytes[..]
+            ));
+            result.expect("Failed to hash text")
+        })
+        .collect();
+}
+
+#[when(expr = "the file is hashed through a reader limited to {int} bytes")]
+fn hash_file_through_limited_reader(world: &mut HashWorld, max_bytes: u64) {
+    let file = world.file.as_ref().expect("No file was created");
+    let reader = std::fs::File::open(file.path()).expect("Failed to open temp file");
+    let result = with_hasher!(world.hasher_type, |H| readable_hash_reader_limited::<H, _>(
+        reader, max_bytes
+    ));
+    world.output = result.unwrap_or_else(|error| format!("error: {error}"));
+}
+
+#[when(expr = "an endless stream is hashed through a reader limited to {int} bytes")]
+fn hash_endless_stream(world: &mut HashWorld, max_bytes: u64) {
+    let result = readable_hash_reader_limited::<StdHasher, _>(std::io::repeat(b'x'), max_bytes);
+    let error = result.expect_err("An endless stream must exceed the limit");
+    assert!(matches!(error, LimitedReadError::LimitExceeded { .. }));
+    world.output = format!("error: {error}");
+}
+
+#[cfg(feature = "std")]
+#[when(expr = "a failing stream is hashed through a reader limited to {int} bytes")]
+fn hash_failing_stream(world: &mut HashWorld, max_bytes: u64) {
+    let result = readable_hash_reader_limited::<StdHasher, _>(FailingReader, max_bytes);
+    let error = result.expect_err("A failing stream must fail the hash");
+    assert!(matches!(error, LimitedReadError::Io(_)));
+    world.output = format!("error: {error}");
+}
+
+#[when("the file is hashed through a counting reader")]
+fn hash_file_through_counting_reader(world: &mut HashWorld) {
+    let file = world.file.as_ref().expect("No file was created");
+    let reader = std::fs::File::open(file.path()).expect("Failed to open temp file");
+    let (output, byte_count) = with_hasher!(world.hasher_type, |H| readable_hash_reader_counted::<
+        H,
+        _,
+    >(reader))
+    .expect("Failed to hash file");
+    world.output = output;
+    world.byte_count = Some(byte_count as usize);
+}
+
+#[then(expr = "the byte count should be {int}")]
+fn check_byte_count(world: &mut HashWorld, expected: usize) {
+    assert_eq!(world.byte_count, Some(expected));
+}
+
+#[cfg(feature = "memmap2")]
+#[then("hashing the file through a memory map should give the same result")]
+fn check_mmap_matches(world: &mut HashWorld) {
+    let file = world.file.as_ref().expect("No file was created");
+    let mapped_output = with_hasher!(world.hasher_type, |H| readable_hash_mmap::<H, _>(
+        file.path()
+    ))
+    .expect("Failed to hash mapped file");
+    assert_eq!(mapped_output, world.output);
+}
+
+#[given("a new directory")]
+fn create_directory(world: &mut HashWorld) {
+    world
+        .directories
+        .push(TempDir::new().expect("Failed to create temp directory"));
+}
+
+#[given(expr = "the directory contains {string} with {string}")]
+fn create_directory_file(world: &mut HashWorld, relative_path: String, contents: String) {
+    let directory = world.directories.last().expect("No directory was created");
+    let file_path = directory.path().join(relative_path);
+    if let Some(parent) = file_path.parent() {
+        std::fs::create_dir_all(parent).expect("Failed to create parent directory");
+    }
+    std::fs::write(file_path, contents).expect("Failed to write file");
+}
+
+#[cfg(unix)]
+#[given(expr = "the directory contains a file named by the bytes {string} with {string}")]
+fn create_directory_file_with_raw_name(world: &mut HashWorld, name: String, contents: String) {
+    use std::os::unix::ffi::OsStrExt as _;
+
+    let directory = world.directories.last().expect("No directory was created");
+    let name = hex::decode(name).expect("Invalid hex file name");
+    let file_path = directory.path().join(std::ffi::OsStr::from_bytes(&name));
+    std::fs::write(file_path, contents).expect("Failed to write file");
+}
+
+#[when("each directory is hashed")]
+fn hash_directories(world: &mut HashWorld) {
+    world.outputs = world
+        .directories
+        .iter()
+        .map(|directory| {
+            with_hasher!(world.hasher_type, |H| readable_hash_dir::<H, _>(
+                directory.path()
+            ))
+        })
+        .collect::<Result<_, _>>()
+        .expect("Failed to hash directory");
+}
+
+#[when(expr = "the first {int} names are taken from a name sequence")]
+fn take_sequence_names(world: &mut HashWorld, count: usize) {
+    world.outputs = with_hasher!(world.hasher_type, |H| ReadableNameSequence::<H>::new()
+        .take(count)
+        .collect());
+}
+
+#[then(expr = "the first result should match the english word hash of {string}")]
+fn check_first_matches_english_word_hash(world: &mut HashWorld, input: String) {
+    let expected = with_hasher!(world.hasher_type, |H| english_word_hash::<H, _>(&input));
+    assert_eq!(world.outputs.first(), Some(&expected));
+}
+
+#[when(expr = "the hashes of {string} are written as a batch separated by newlines")]
+fn write_hash_batch(world: &mut HashWorld, inputs: String) {
+    let inputs: Vec<&str> = inputs.split(',').collect();
+    let mut written = Vec::new();
+    with_hasher!(world.hasher_type, |H| readable_hash_batch_write::<H, _, _>(
+        &inputs,
+        &mut written,
+        b'\n'
+    ))
+    .expect("Failed to write batch");
+    world.output = String::from_utf8(written).expect("Invalid UTF-8");
+}
+
+#[then(expr = "splitting the result on newlines should give the english word hashes of {string}")]
+fn check_batch_hashes(world: &mut HashWorld, inputs: String) {
+    let expected: Vec<String> = inputs
+        .split(',')
+        .map(|input| with_hasher!(world.hasher_type, |H| english_word_hash::<H, _>(input)))
+        .collect();
+    let actual: Vec<&str> = world.output.split('\n').collect();
+    assert_eq!(actual, expected);
+}
+
+#[then(expr = "the result should be {string} when newlines are escaped")]
+fn check_escaped_result(world: &mut HashWorld, expected: String) {
+    assert_eq!(world.output.replace('\n', "\\n"), expected);
+}
+
+#[when(expr = "weighted hashes are generated for {string}")]
+fn generate_weighted_hashes(world: &mut HashWorld, configurations: String) {
+    world.outputs = configurations
+        .split(';')
+        .map(|configuration| {
+            let inputs: Vec<(&str, u32)> = configuration
+                .split(',')
+                .map(|entry| {
+                    let (input, weight) = entry.split_once('=').expect("Expected input=weight");
+                    (input, weight.parse().expect("Invalid weight"))
+                })
+                .collect();
+            with_hasher!(world.hasher_type, |H| readable_hash_weighted::<H, _>(
+                &inputs
+            ))
+        })
+        .collect();
+}
+
+#[then("the second result should share a longer prefix with the first than the third")]
+fn check_prefix_influence(world: &mut HashWorld) {
+    let common_prefix = |left: &str, right: &str| {
+        left.bytes()
+            .zip(right.bytes())
+            .take_while(|(left_byte, right_byte)| left_byte == right_byte)
+            .count()
     };
+    let low_weight_change = common_prefix(&world.outputs[0], &world.outputs[1]);
+    let high_weight_change = common_prefix(&world.outputs[0], &world.outputs[2]);
+    assert!(
+        low_weight_change > high_weight_change,
+        "Results: {:?}",
+        world.outputs
+    );
+}
+
+#[then(expr = "the first result should be {string}")]
+fn check_first_result(world: &mut HashWorld, expected: String) {
+    assert_eq!(world.outputs.first(), Some(&expected));
+}
+
+#[when(expr = "the value {int} is hashed in both byte orders")]
+fn hash_value_both_byte_orders(world: &mut HashWorld, value: u64) {
+    world.outputs = with_hasher!(world.hasher_type, |H| vec![
+        readable_hash_be::<H, _>(value),
+        readable_hash_le::<H, _>(value),
+    ]);
+}
+
+#[when(expr = "the maps {string} and {string} are hashed")]
+fn hash_maps(world: &mut HashWorld, first: String, second: String) {
+    world.outputs = [first, second]
+        .iter()
+        .map(|entries| {
+            let pairs: Vec<(&str, &str)> = entries
+                .split(',')
+                .filter(|entry| !entry.is_empty())
+                .map(|entry| entry.split_once('=').expect("Expected key=value"))
+                .collect();
+            with_hasher!(world.hasher_type, |H| readable_hash_map::<H, _, _, _>(
+                pairs
+            ))
+        })
+        .collect();
+}
+
+#[when(expr = "a readable code with {int} digits is generated")]
+fn generate_readable_code(world: &mut HashWorld, digits: usize) {
+    world.output = with_hasher!(world.hasher_type, |H| readable_code::<H, _>(
+        &world.input,
+        digits
+    ));
+}
+
+#[when(expr = "a version {int} readable code is generated")]
+fn generate_readable_code_v2(world: &mut HashWorld, version: u8) {
+    world.output = with_hasher!(world.hasher_type, |H| readable_code_v2::<H, _>(
+        &world.input,
+        version
+    ));
+}
+
+#[then(expr = "the code should parse as version {int} with a valid checksum")]
+fn check_code_v2_round_trip(world: &mut HashWorld, version: u8) {
+    assert_eq!(parse_readable_code_v2(&world.output), Some((version, true)));
+}
+
+#[then(
+    expr = "every single-letter change to the word should parse as version {int} with an invalid checksum"
+)]
+fn check_code_v2_corruptions(world: &mut HashWorld, version: u8) {
+    let parts: Vec<&str> = world.output.split('-').collect();
+    let [prefix, word, suffix] = parts[..] else {
+        panic!("Expected three parts in {}", world.output);
+    };
+    for (i, original) in word.char_indices() {
+        for replacement in ('a'..='z').filter(|letter| *letter != original) {
+            let mut corrupted = word.to_string();
+            corrupted.replace_range(i..=i, &replacement.to_string());
+            let code = format!("{prefix}-{corrupted}-{suffix}");
+            assert_eq!(
+                parse_readable_code_v2(&code),
+                Some((version, false)),
+                "{code}"
+            );
+        }
+    }
+}
+
+#[then(expr = "the code {string} should not parse")]
+fn check_code_v2_unparsable(_world: &mut HashWorld, code: String) {
+    assert_eq!(parse_readable_code_v2(&code), None);
+}
+
+#[then("the readable code should be valid")]
+fn check_readable_code_valid(world: &mut HashWorld) {
+    assert!(validate_readable_code(&world.output), "{}", world.output);
+}
+
+#[then(expr = "the code {string} should be valid")]
+fn check_code_valid(_world: &mut HashWorld, code: String) {
+    assert!(validate_readable_code(&code));
+}
+
+#[then(expr = "the code {string} should be invalid")]
+fn check_code_invalid(_world: &mut HashWorld, code: String) {
+    assert!(!validate_readable_code(&code));
+}
+
+#[then("every single-digit change to the readable code should be invalid")]
+fn check_readable_code_corruptions(world: &mut HashWorld) {
+    let (word, number) = world.output.rsplit_once('-').expect("Expected a code");
+    for (i, original) in number.char_indices() {
+        for replacement in ('0'..='9').filter(|digit| *digit != original) {
+            let mut corrupted = number.to_string();
+            corrupted.replace_range(i..=i, &replacement.to_string());
+            let code = format!("{word}-{corrupted}");
+            assert!(!validate_readable_code(&code), "{code} should be invalid");
+        }
+    }
+}
+
+#[when("the checksummed hash is generated")]
+fn generate_checksummed_hash(world: &mut HashWorld) {
+    world.output = with_hasher!(world.hasher_type, |H| readable_hash_with_checksum::<H, _>(
+        &world.input
+    ));
+}
+
+#[then("the checksummed hash should verify")]
+fn check_checksummed_hash_verifies(world: &mut HashWorld) {
+    assert!(
+        verify_readable_hash_checksum(&world.output),
+        "{}",
+        world.output
+    );
+}
+
+#[then("every single-letter change to the checksummed hash should fail")]
+fn check_checksummed_hash_corruptions(world: &mut HashWorld) {
+    let (word, checksum) = world.output.rsplit_once('-').expect("Expected a checksum");
+    for (i, original) in word.char_indices() {
+        for replacement in ('a'..='z').filter(|letter| *letter != original) {
+            let mut corrupted = word.to_string();
+            corrupted.replace_range(i..=i, &replacement.to_string());
+            let hash = format!("{corrupted}-{checksum}");
+            assert!(!verify_readable_hash_checksum(&hash), "{hash} should fail");
+        }
+    }
+}
+
+#[then(expr = "the checksummed hash {string} should {word}")]
+fn check_checksummed_hash(_world: &mut HashWorld, hash: String, outcome: String) {
+    let expected = match outcome.as_str() {
+        "verify" => true,
+        "fail" => false,
+        _ => panic!("Unknown outcome: {outcome}"),
+    };
+    assert_eq!(verify_readable_hash_checksum(&hash), expected, "{hash}");
+}
+
+/// Format explained tokens as `text:XX` pairs joined by commas.
+fn format_explanation(explained: &[(&str, u8)]) -> Vec<String> {
+    explained
+        .iter()
+        .map(|(text, byte)| format!("{text}:{byte:02X}"))
+        .collect()
+}
+
+#[when("the tagged hash is generated")]
+fn generate_tagged_hash(world: &mut HashWorld) {
+    let (word, tag) = with_hasher!(world.hasher_type, |H| readable_hash_tagged::<H, _>(
+        &world.input
+    ));
+    world.output = word;
+    world.outputs = vec![tag];
+}
+
+#[then(expr = "the tag should be {string}")]
+fn check_tag(world: &mut HashWorld, expected: String) {
+    assert_eq!(world.outputs.first(), Some(&expected));
+}
+
+#[when(expr = "the input is hashed with a table of {string} and the byte in hex")]
+fn generate_table_hash(world: &mut HashWorld, prefix: String) {
+    let names: Vec<String> = (0..=255)
+        .map(|byte| format!("{prefix}{byte:02x}"))
+        .collect();
+    let table: [&str; 256] = std::array::from_fn(|index| names[index].as_str());
+    world.output = with_hasher!(world.hasher_type, |H| readable_hash_with_table::<H, _>(
+        &world.input,
+        &table
+    ));
+}
+
+#[then(
+    expr = "the result should be the std digest of the input in hex with {string} before each byte"
+)]
+fn check_table_hash(world: &mut HashWorld, prefix: String) {
+    let mut hasher: StdHasher = StdHasher::default();
+    hasher.update(world.input.as_bytes());
+    let mut digest = [0u8; 8];
+    hasher.finalize().read(&mut digest);
+    let expected: String = digest
+        .iter()
+        .map(|byte| format!("{prefix}{byte:02x}"))
+        .collect();
+    assert_eq!(world.output, expected);
+}
+
+#[when(expr = "the english word hash is generated with separator {string}")]
+fn generate_separated_hash(world: &mut HashWorld, separator: String) {
+    world.output = with_hasher!(world.hasher_type, |H| readable_hash_with_separator::<H, _>(
+        &world.input,
+        &separator
+    ));
+}
+
+#[then("the tag should start the std digest of the input")]
+fn check_tag_digest(world: &mut HashWorld) {
+    let mut hasher: StdHasher = StdHasher::default();
+    hasher.update(world.input.as_bytes());
+    let mut digest = [0u8; 8];
+    hasher.finalize().read(&mut digest);
+    let tag = world.outputs.first().expect("No tag was generated");
+    assert!(hex::encode(digest).starts_with(tag.as_str()));
+}
+
+#[when(expr = "sortable keys are generated for {int} numbered inputs")]
+fn generate_sortable_keys(world: &mut HashWorld, count: usize) {
+    world.outputs = (0..count)
+        .map(|i| sortable_readable_key::<StdHasher, _>(format!("input-{i}")))
+        .collect();
+}
+
+#[when("the sortable key of the input is generated")]
+fn generate_sortable_key(world: &mut HashWorld) {
+    world.output = with_hasher!(world.hasher_type, |H| sortable_readable_key::<H, _>(
+        &world.input
+    ));
+}
+
+#[then("sorting the keys should match sorting the std digests of the inputs")]
+fn check_key_order(world: &mut HashWorld) {
+    let digests: Vec<[u8; 8]> = (0..world.outputs.len())
+        .map(|i| {
+            let mut hasher: StdHasher = StdHasher::default();
+            hasher.update(format!("input-{i}").as_bytes());
+            let mut digest = [0u8; 8];
+            hasher.finalize().read(&mut digest);
+            digest
+        })
+        .collect();
+    let mut by_key: Vec<usize> = (0..world.outputs.len()).collect();
+    by_key.sort_by(|left, right| world.outputs[*left].cmp(&world.outputs[*right]));
+    let mut by_digest: Vec<usize> = (0..digests.len()).collect();
+    by_digest.sort_by(|left, right| digests[*left].cmp(&digests[*right]));
+    assert_eq!(by_key, by_digest);
+}
+
+#[when("the input is explained")]
+fn explain_input(world: &mut HashWorld) {
+    let explained = with_hasher!(world.hasher_type, |H| readable_hash_explain::<H, _>(
+        &world.input
+    ));
+    world.outputs = format_explanation(&explained);
+}
+
+#[then(expr = "the estimated bits for {int} characters should be {int}")]
+fn check_estimated_word_bits(_world: &mut HashWorld, word_len: usize, bits: usize) {
+    assert_eq!(english_word::estimated_word_bits(word_len), bits);
+}
+
+#[then(expr = "the estimated bits of the result should be within {int} bits of the bits it read")]
+fn check_estimate_against_counted(world: &mut HashWorld, tolerance: usize) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let (word, bytes_read) = english_word::generate_word_counted(&mut reader);
+    let bits_read = bytes_read * usize::from(english_word::WORD_PROBABILITY_BITS);
+    assert!(english_word::estimated_word_bits(word.len()).abs_diff(bits_read) <= tolerance);
+}
+
+#[when("a word is tried from the entropy")]
+fn try_word_from_entropy(world: &mut HashWorld) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = match english_word::try_generate_word(&mut reader) {
+        Ok(word) => word,
+        Err(error) => format!("{error:?}"),
+    };
+}
+
+#[when(expr = "a word of at least {int} characters is tried from the entropy")]
+fn try_target_len_word_from_entropy(world: &mut HashWorld, target_len: usize) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = match english_word::try_generate_word_with_target_len(&mut reader, target_len) {
+        Ok(word) => word,
+        Err(error) => format!("{error:?}"),
+    };
+}
+
+#[when(expr = "the entropy is explained with target length {int}")]
+fn explain_entropy(world: &mut HashWorld, target_len: usize) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let explained = english_word::explain_word_with_target_len(&mut reader, target_len);
+    world.outputs = format_explanation(&explained);
+}
+
+#[then(expr = "the explanation should be {string}")]
+fn check_explanation(world: &mut HashWorld, expected: String) {
+    assert_eq!(world.outputs.join(","), expected);
+}
+
+#[then("the explained tokens should spell the english word hash of the input")]
+fn check_explained_word(world: &mut HashWorld) {
+    let word: String = world
+        .outputs
+        .iter()
+        .map(|pair| pair.rsplit_once(':').expect("Expected text:XX").0)
+        .collect();
+    let expected = with_hasher!(world.hasher_type, |H| english_word_hash::<H, _>(
+        &world.input
+    ));
+    assert_eq!(word, expected);
+}
+
+#[then("the explained bytes should start the std digest of the input")]
+fn check_explained_bytes(world: &mut HashWorld) {
+    let mut hasher: StdHasher = StdHasher::default();
+    hasher.update(world.input.as_bytes());
+    let mut digest = [0u8; 8];
+    let digest_len = hasher.finalize().read(&mut digest);
+    let digest = hex::encode_upper(&digest[..digest_len]);
+    let explained_bytes: String = world
+        .outputs
+        .iter()
+        .map(|pair| pair.rsplit_once(':').expect("Expected text:XX").1)
+        .collect();
+    assert!(
+        digest.starts_with(&explained_bytes),
+        "{explained_bytes} is not a prefix of {digest}"
+    );
+}
+
+#[then("all results should be equal")]
+fn check_results_equal(world: &mut HashWorld) {
+    assert!(
+        world.outputs.windows(2).all(|pair| pair[0] == pair[1]),
+        "Expected equal results, got: {:?}",
+        world.outputs
+    );
+}
+
+#[then("all results should be non-empty")]
+fn check_results_non_empty(world: &mut HashWorld) {
+    assert!(
+        world.outputs.iter().all(|output| !output.is_empty()),
+        "Expected non-empty results, got: {:?}",
+        world.outputs
+    );
+}
+
+#[then(expr = "the result should match the english word hash of {string}")]
+fn check_matches_english_word_hash(world: &mut HashWorld, input: String) {
+    let expected = with_hasher!(world.hasher_type, |H| english_word_hash::<H, _>(&input));
+    assert_eq!(world.output, expected);
+}
+
+#[then(expr = "the result should not match the english word hash of {string}")]
+fn check_differs_from_english_word_hash(world: &mut HashWorld, input: String) {
+    let unexpected = with_hasher!(world.hasher_type, |H| english_word_hash::<H, _>(&input));
+    assert_ne!(world.output, unexpected);
 }
 
 #[then(expr = "the result should be {string}")]
@@ -63,6 +1022,15 @@ fn check_length(world: &mut HashWorld, expected_length: usize) {
     );
 }
 
+#[then(expr = "the result should have at least {int} characters")]
+fn check_min_length(world: &mut HashWorld, min_length: usize) {
+    assert!(
+        world.output.len() >= min_length,
+        "Expected at least {min_length} characters in '{}'",
+        world.output
+    );
+}
+
 #[then("the result should be a single word")]
 fn check_single_word(world: &mut HashWorld) {
     assert!(
@@ -72,6 +1040,18 @@ fn check_single_word(world: &mut HashWorld) {
     );
 }
 
+#[then("the result should be uppercase")]
+fn check_uppercase(world: &mut HashWorld) {
+    assert!(
+        world
+            .output
+            .chars()
+            .all(|character| character.is_ascii_uppercase()),
+        "Expected only uppercase letters, got: '{}'",
+        world.output
+    );
+}
+
 #[given(expr = "the entropy bytes {string}")]
 fn set_entropy(world: &mut HashWorld, hex: String) {
     world.entropy = hex::decode(&hex).expect("Invalid hex string");
@@ -83,6 +1063,916 @@ fn generate_word_from_entropy(world: &mut HashWorld) {
     world.output = english_word::generate_word(&mut reader);
 }
 
+#[cfg(feature = "blake3")]
+#[then(
+    expr = "squeezing {int} bytes of the blake3 output of {string} in chunks of {int} should match a one-shot squeeze"
+)]
+fn check_blake3_squeeze(_world: &mut HashWorld, len: usize, input: String, chunk_len: usize) {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(input.as_bytes());
+    let mut expected = vec![0u8; len];
+    hasher.finalize_xof().fill(&mut expected);
+    let mut reader = Blake3Reader::new(hasher.finalize_xof());
+    let mut squeezed = vec![0u8; len];
+    for chunk in squeezed.chunks_mut(chunk_len) {
+        assert_eq!(reader.read(chunk), chunk.len());
+    }
+    assert_eq!(squeezed, expected);
+}
+
+#[when(expr = "a word is generated from the entropy split after {int} bytes and chained")]
+fn generate_word_from_chained_entropy(world: &mut HashWorld, split: usize) {
+    let (first, second) = world.entropy.split_at(split);
+    let mut reader = ChainReader::new(SliceReader::new(first), SliceReader::new(second));
+    world.output = english_word::generate_word(&mut reader);
+}
+
+#[when(expr = "{int} bytes are read from the entropy repeated in chunks of {int}")]
+fn read_repeated_entropy(world: &mut HashWorld, len: usize, chunk_len: usize) {
+    let mut reader = RepeatReader::new(&world.entropy);
+    let mut bytes = vec![0u8; len];
+    for chunk in bytes.chunks_mut(chunk_len) {
+        assert_eq!(reader.read(chunk), chunk.len());
+    }
+    world.output = hex::encode_upper(bytes);
+}
+
+#[when(expr = "a passphrase of {int} words is generated from the entropy repeated")]
+fn generate_passphrase_from_repeated_entropy(world: &mut HashWorld, word_count: usize) {
+    let mut reader = RepeatReader::new(&world.entropy);
+    world.output = english_word::generate_passphrase(&mut reader, word_count, "-");
+}
+
+#[when("a word is generated from the entropy through a byte iterator")]
+fn generate_word_from_iterator(world: &mut HashWorld) {
+    let mut reader = IterReader::new(world.entropy.iter().copied());
+    world.output = english_word::generate_word(&mut reader);
+}
+
+/// Reader whose every read fails.
+#[cfg(feature = "std")]
+struct FailingReader;
+
+#[cfg(feature = "std")]
+impl std::io::Read for FailingReader {
+    fn read(&mut self, _buffer: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("read failed"))
+    }
+}
+
+#[cfg(feature = "std")]
+#[when("a word is generated from the entropy through an io reader")]
+fn generate_word_from_io_reader(world: &mut HashWorld) {
+    let mut reader = ReadAdapter::new(world.entropy.as_slice());
+    world.output = english_word::generate_word(&mut reader);
+}
+
+#[cfg(feature = "std")]
+#[when("a word is generated from the entropy through an io reader that then fails")]
+fn generate_word_from_failing_io_reader(world: &mut HashWorld) {
+    use std::io::Read as _;
+
+    let mut reader = ReadAdapter::new(world.entropy.as_slice().chain(FailingReader));
+    world.output = english_word::generate_word(&mut reader);
+}
+
+#[cfg(feature = "std")]
+#[then("an io reader over the entropy should report an unknown length until it is read to the end")]
+fn check_io_reader_remaining(world: &mut HashWorld) {
+    let mut reader = ReadAdapter::new(world.entropy.as_slice());
+    let mut buffer = vec![0u8; world.entropy.len()];
+    assert_eq!(reader.remaining(), None);
+    assert_eq!(reader.read(&mut buffer), world.entropy.len());
+    assert_eq!(reader.remaining(), None);
+    assert_eq!(reader.read(&mut buffer), 0);
+    assert_eq!(reader.remaining(), Some(0));
+}
+
+#[when("a capitalized word is generated from the entropy")]
+fn generate_capitalized_word(world: &mut HashWorld) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_word_capitalized(&mut reader);
+}
+
+#[when(expr = "a word is generated from the entropy with generator options {string}")]
+fn generate_word_with_generator(world: &mut HashWorld, options: String) {
+    let deny_list: Vec<&str> = options
+        .split(',')
+        .filter_map(|option| option.strip_prefix("deny="))
+        .collect();
+    let mut builder = english_word::WordGenerator::builder().deny_list(&deny_list);
+    for option in options.split(',').filter(|option| !option.is_empty()) {
+        builder = match option.split_once('=') {
+            Some(("min", len)) => builder.min_len(len.parse().expect("Invalid min length")),
+            Some(("max", len)) => builder.max_len(len.parse().expect("Invalid max length")),
+            Some(("deny", _)) => builder,
+            None if option == "capitalize" => builder.capitalize(true),
+            _ => panic!("Unknown generator option: {option}"),
+        };
+    }
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = builder.build().generate(&mut reader);
+}
+
+#[when("a word is generated from the entropy with its byte count")]
+fn generate_counted_word(world: &mut HashWorld) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let (word, bytes_read) = english_word::generate_word_counted(&mut reader);
+    world.output = word;
+    world.byte_count = Some(bytes_read);
+}
+
+#[given(expr = "{int} bytes of seeded entropy")]
+fn set_seeded_entropy(world: &mut HashWorld, len: usize) {
+    world.entropy = seeded_entropy(0, len);
+}
+
+#[when(
+    expr = "a password of at least {int} characters and {int} words is generated from the entropy"
+)]
+fn generate_password_from_entropy(world: &mut HashWorld, min_chars: usize, min_words: usize) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_password(&mut reader, min_chars, min_words);
+}
+
+#[then(expr = "the model should have {int} tokens")]
+fn check_token_count(_world: &mut HashWorld, count: usize) {
+    assert_eq!(english_word::token_count(), count);
+}
+
+#[then(expr = "token {int} should read {string}")]
+fn check_token_text(_world: &mut HashWorld, token_id: u16, text: String) {
+    assert_eq!(english_word::token_text_for(token_id), Some(text.as_str()));
+}
+
+#[then(expr = "token {int} should not exist")]
+fn check_missing_token(_world: &mut HashWorld, token_id: u16) {
+    assert_eq!(english_word::token_text_for(token_id), None);
+}
+
+#[then("every single-byte reader should give a non-empty word")]
+fn check_single_byte_words(_world: &mut HashWorld) {
+    for byte in 0..=u8::MAX {
+        let word = english_word::generate_word(&mut SliceReader::new(&[byte]));
+        assert!(!word.is_empty(), "Empty word for byte {byte:#04X}");
+    }
+}
+
+#[then(expr = "a counting reader over the entropy should count {int} bytes for a {word}")]
+fn check_counting_reader(world: &mut HashWorld, expected: usize, generator: String) {
+    let mut reader = CountingReader::new(SliceReader::new(&world.entropy));
+    match generator.as_str() {
+        "word" => {
+            english_word::generate_word(&mut reader);
+            let mut slice_reader = SliceReader::new(&world.entropy);
+            let (_, bytes_read) = english_word::generate_word_counted(&mut slice_reader);
+            assert_eq!(reader.bytes_read(), bytes_read);
+        }
+        "passphrase" => {
+            english_word::generate_passphrase(&mut reader, 2, "-");
+        }
+        _ => panic!("Unknown generator: {generator}"),
+    }
+    assert_eq!(reader.bytes_read(), expected);
+    assert_eq!(reader.remaining(), Some(world.entropy.len() - expected));
+}
+
+#[when("a word is generated by draining the entropy from a deque")]
+fn generate_word_from_deque(world: &mut HashWorld) {
+    let mut deque: VecDeque<u8> = world.entropy.iter().copied().collect();
+    world.output = english_word::generate_word(&mut DequeReader::new(&mut deque));
+    world.entropy = deque.into();
+}
+
+#[then(expr = "the entropy left should be {string}")]
+fn check_entropy_left(world: &mut HashWorld, expected: String) {
+    assert_eq!(hex::encode_upper(&world.entropy), expected);
+}
+
+#[when(expr = "the entropy is read in groups of {string} bits")]
+fn read_entropy_bits(world: &mut HashWorld, counts: String) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let mut bit_reader = BitReader::new(&mut reader);
+    world.outputs = read_bit_groups(&mut bit_reader, &counts);
+}
+
+#[when(expr = "the entropy is checked for {int} bits and then read in groups of {string} bits")]
+fn check_then_read_entropy_bits(world: &mut HashWorld, checked: usize, counts: String) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let mut bit_reader = BitReader::new(&mut reader);
+    assert!(bit_reader.has_more_bits(checked));
+    world.outputs = read_bit_groups(&mut bit_reader, &counts);
+}
+
+/// Read each comma-separated group of bits, `u8` through `read_u8`.
+fn read_bit_groups(bit_reader: &mut BitReader<'_, SliceReader<'_>>, counts: &str) -> Vec<String> {
+    counts
+        .split(',')
+        .map(|count| {
+            let value = match count {
+                "u8" => bit_reader.read_u8().map(u32::from),
+                _ => bit_reader.read_bits(count.parse().expect("Invalid bit count")),
+            };
+            value.map_or_else(|| "none".to_string(), |value| value.to_string())
+        })
+        .collect()
+}
+
+#[then(expr = "reading the entropy in groups of {string} bits should match reading it bit by bit")]
+fn check_bits_against_reference(world: &mut HashWorld, counts: String) {
+    let counts: Vec<usize> = counts
+        .split(',')
+        .map(|count| count.parse().expect("Invalid bit count"))
+        .collect();
+    let mut reader = SliceReader::new(&world.entropy);
+    let mut bit_reader = BitReader::new(&mut reader);
+    let mut bit_pos = 0;
+    for count in counts.iter().cycle() {
+        let expected = (bit_pos + count <= world.entropy.len() * 8).then(|| {
+            (bit_pos..bit_pos + count).fold(0u32, |value, i| {
+                (value << 1) | u32::from((world.entropy[i / 8] >> (7 - i % 8)) & 1)
+            })
+        });
+        assert_eq!(
+            bit_reader.read_bits(*count),
+            expected,
+            "{count} bits at bit {bit_pos}"
+        );
+        if expected.is_none() {
+            break;
+        }
+        bit_pos += count;
+    }
+}
+
+#[when("words are collected from the entropy")]
+fn collect_words(world: &mut HashWorld) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.outputs = english_word::generate_words(&mut reader).collect();
+}
+
+#[when(expr = "{int} words are taken from the entropy repeated")]
+fn take_repeated_words(world: &mut HashWorld, count: usize) {
+    let mut reader = RepeatReader::new(&world.entropy);
+    world.outputs = english_word::generate_words(&mut reader)
+        .take(count)
+        .collect();
+}
+
+#[when("words are generated from the entropy until it runs out")]
+fn generate_counted_words(world: &mut HashWorld) {
+    let mut offset = 0;
+    world.outputs.clear();
+    while offset < world.entropy.len() {
+        let mut reader = SliceReader::new(&world.entropy[offset..]);
+        let (word, bytes_read) = english_word::generate_word_counted(&mut reader);
+        world.outputs.push(word);
+        offset += bytes_read;
+    }
+}
+
+#[cfg(feature = "timing")]
+#[when("a word is generated from the entropy with timing")]
+fn generate_timed_word(world: &mut HashWorld) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let (word, elapsed) = english_word::generate_word_timed(&mut reader);
+    assert!(elapsed >= std::time::Duration::ZERO);
+    world.output = word;
+}
+
+#[when("a word is generated from the entropy with its end reason")]
+fn generate_word_with_status(world: &mut HashWorld) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let (word, end_reason) = english_word::generate_word_status(&mut reader);
+    world.output = word;
+    world.end_reason = Some(end_reason);
+}
+
+#[then(expr = "the word should have ended {word}")]
+fn check_end_reason(world: &mut HashWorld, expected: String) {
+    let expected = match expected.as_str() {
+        "naturally" => EndReason::Natural,
+        "exhausted" => EndReason::Exhausted,
+        other => panic!("Unknown end reason: {other}"),
+    };
+    assert_eq!(world.end_reason, Some(expected));
+}
+
+#[when(expr = "a word of {int} to {int} tokens is generated from the entropy")]
+fn generate_word_in_token_window(world: &mut HashWorld, min_tokens: usize, max_tokens: usize) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_word_token_window(&mut reader, min_tokens, max_tokens);
+}
+
+#[when(expr = "a word of at most {int} characters is generated from the entropy")]
+fn generate_word_with_max_len(world: &mut HashWorld, max_len: usize) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_word_with_max_len(&mut reader, max_len);
+}
+
+#[when(expr = "a word of {int} to {int} characters is generated from the entropy")]
+fn generate_word_in_range(world: &mut HashWorld, min_len: usize, max_len: usize) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_word_in_range(&mut reader, min_len, max_len);
+}
+
+#[when(expr = "a word is generated from the entropy stopping after {int} characters")]
+fn generate_word_with_callback(world: &mut HashWorld, max_prefix_len: usize) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let mut partials = Vec::new();
+    world.output = english_word::generate_word_cb(&mut reader, |partial| {
+        partials.push(partial.to_string());
+        partial.len() < max_prefix_len
+    });
+    world.outputs = partials;
+}
+
+/// Deterministic pseudo-random entropy for seed `seed`.
+fn seeded_entropy(seed: usize, len: usize) -> Vec<u8> {
+    use std::hash::{DefaultHasher, Hash as _, Hasher as _};
+
+    (0..len.div_ceil(8))
+        .flat_map(|block| {
+            let mut hasher = DefaultHasher::new();
+            (seed, block).hash(&mut hasher);
+            hasher.finish().to_le_bytes()
+        })
+        .take(len)
+        .collect()
+}
+
+#[when(expr = "words are generated from {int} seeds with a soft maximum of {int}")]
+fn generate_soft_max_words(world: &mut HashWorld, seeds: usize, soft_max_len: usize) {
+    let opts = english_word::GenOpts {
+        soft_max_len: Some(soft_max_len),
+        ..english_word::GenOpts::default()
+    };
+    world.outputs = (0..seeds)
+        .map(|seed| {
+            let entropy = seeded_entropy(seed, 64);
+            english_word::generate_word_opts(&mut SliceReader::new(&entropy), &opts)
+        })
+        .collect();
+}
+
+#[when(expr = "a word is generated from the entropy with the {word} end policy")]
+fn generate_word_with_end_policy(world: &mut HashWorld, policy: String) {
+    let end_policy = match policy.as_str() {
+        "entropy" => english_word::EndPolicy::Entropy,
+        "most-likely" => english_word::EndPolicy::MostLikely,
+        _ => panic!("Unknown end policy: {policy}"),
+    };
+    let opts = english_word::GenOpts {
+        end_policy,
+        ..english_word::GenOpts::default()
+    };
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_word_opts(&mut reader, &opts);
+}
+
+#[when(expr = "a word is generated from the entropy padded to {int} characters")]
+fn generate_padded_word(world: &mut HashWorld, min_len: usize) {
+    let opts = english_word::GenOpts {
+        on_short: english_word::OnShort::Pad { min_len },
+        ..english_word::GenOpts::default()
+    };
+    let mut reader = SliceReader::new(&world.entropy);
+    let (word, padded) = english_word::generate_word_opts_padded(&mut reader, &opts);
+    world.output = word;
+    world.padded = Some(padded);
+}
+
+#[then(expr = "the word should be padded")]
+fn check_padded(world: &mut HashWorld) {
+    assert_eq!(world.padded, Some(true));
+}
+
+#[then(expr = "the word should not be padded")]
+fn check_not_padded(world: &mut HashWorld) {
+    assert_eq!(world.padded, Some(false));
+}
+
+#[when(expr = "words are generated from {int} seeds without options")]
+fn generate_default_opts_words(world: &mut HashWorld, seeds: usize) {
+    world.outputs = (0..seeds)
+        .map(|seed| {
+            let entropy = seeded_entropy(seed, 64);
+            let opts_word = english_word::generate_word_opts(
+                &mut SliceReader::new(&entropy),
+                &english_word::GenOpts::default(),
+            );
+            assert_eq!(
+                opts_word,
+                english_word::generate_word(&mut SliceReader::new(&entropy))
+            );
+            opts_word
+        })
+        .collect();
+}
+
+#[then(expr = "the results should have checksum {string}")]
+fn check_results_checksum(world: &mut HashWorld, expected: String) {
+    // FNV-1a, so the checksum does not depend on the standard hasher
+    let checksum = world
+        .outputs
+        .join(",")
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    assert_eq!(format!("{checksum:016x}"), expected);
+}
+
+#[then(expr = "at most {int} results should be longer than {int} characters")]
+fn check_long_results(world: &mut HashWorld, max_count: usize, len: usize) {
+    let long_count = world
+        .outputs
+        .iter()
+        .filter(|output| output.len() > len)
+        .count();
+    assert!(
+        long_count <= max_count,
+        "{long_count} results longer than {len}: {:?}",
+        world.outputs
+    );
+}
+
+#[then(expr = "at least {int} results should be longer than {int} characters")]
+fn check_min_long_results(world: &mut HashWorld, min_count: usize, len: usize) {
+    let long_count = world
+        .outputs
+        .iter()
+        .filter(|output| output.len() > len)
+        .count();
+    assert!(
+        long_count >= min_count,
+        "{long_count} results longer than {len}"
+    );
+}
+
+#[when(expr = "a word is generated from the entropy with the infix {string}")]
+fn generate_word_with_infix(world: &mut HashWorld, infix: String) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_word_infix(&mut reader, &infix);
+}
+
+#[then(expr = "the infix {string} should appear exactly once")]
+fn check_infix_once(world: &mut HashWorld, infix: String) {
+    assert_eq!(world.output.matches(&infix).count(), 1, "{}", world.output);
+}
+
+#[then(
+    expr = "without the infix {string} the result should be the word from the remaining entropy"
+)]
+fn check_infix_removed(world: &mut HashWorld, infix: String) {
+    let mut reader = SliceReader::new(&world.entropy[1..]);
+    assert_eq!(
+        world.output.replacen(&infix, "", 1),
+        english_word::generate_word(&mut reader)
+    );
+}
+
+#[when(expr = "variants {int} to {int} are generated from the entropy")]
+fn generate_word_variants(world: &mut HashWorld, first: u32, last: u32) {
+    world.outputs = (first..=last)
+        .map(|variant| {
+            let mut reader = SliceReader::new(&world.entropy);
+            english_word::generate_word_variant(&mut reader, variant)
+        })
+        .collect();
+}
+
+#[when(expr = "variant {int} is generated from the entropy twice")]
+fn generate_word_variant_twice(world: &mut HashWorld, variant: u32) {
+    world.outputs = (0..2)
+        .map(|_| {
+            let mut reader = SliceReader::new(&world.entropy);
+            english_word::generate_word_variant(&mut reader, variant)
+        })
+        .collect();
+}
+
+#[when(expr = "leet words are generated from the entropy with intensities {string}")]
+fn generate_leet_words(world: &mut HashWorld, intensities: String) {
+    world.outputs = intensities
+        .split(',')
+        .map(|intensity| {
+            let intensity = intensity.parse().expect("Invalid intensity");
+            let mut reader = SliceReader::new(&world.entropy);
+            english_word::generate_word_leet(&mut reader, intensity)
+        })
+        .collect();
+}
+
+#[then(expr = "the results should have {string} substituted characters")]
+fn check_leet_counts(world: &mut HashWorld, expected: String) {
+    let counts: Vec<String> = world
+        .outputs
+        .iter()
+        .map(|word| {
+            word.chars()
+                .filter(char::is_ascii_digit)
+                .count()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(counts.join(","), expected);
+}
+
+#[then("all results should unleet to the word from the entropy after the seed")]
+fn check_unleet(world: &mut HashWorld) {
+    let expected = english_word::generate_word(&mut SliceReader::new(&world.entropy[8..]));
+    for word in &world.outputs {
+        assert_eq!(english_word::unleet(word), expected);
+    }
+}
+
+#[when(expr = "a word of {int} phonemes is generated from the entropy")]
+fn generate_word_with_phonemes(world: &mut HashWorld, target_phonemes: usize) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let (word, phonemes) = english_word::generate_word_phonemes(&mut reader, target_phonemes);
+    world.output = word;
+    world.byte_count = Some(phonemes);
+}
+
+#[then(expr = "the word should have at least {int} phonemes")]
+fn check_min_phonemes(world: &mut HashWorld, min_phonemes: usize) {
+    let phonemes = world.byte_count.expect("No phoneme count");
+    assert!(
+        phonemes >= min_phonemes,
+        "{} has {phonemes} phonemes",
+        world.output
+    );
+}
+
+#[then(expr = "the word should have {int} phonemes")]
+fn check_phonemes(world: &mut HashWorld, expected: usize) {
+    assert_eq!(world.byte_count, Some(expected));
+}
+
+#[when(expr = "a word at least {float} away from {string} is generated from the entropy")]
+fn generate_dissimilar_word(world: &mut HashWorld, min_distance: f64, avoid: String) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_dissimilar(&mut reader, &avoid, min_distance);
+}
+
+#[then(expr = "the result should be at least {float} away from {string}")]
+fn check_dissimilar(world: &mut HashWorld, min_distance: f64, avoid: String) {
+    let distance = 1.0 - english_word::readable_similarity(&world.output, &avoid);
+    assert!(
+        distance >= min_distance,
+        "{} is only {distance} away from {avoid}",
+        world.output
+    );
+}
+
+#[then(expr = "the similarity of {string} and {string} should be {float}")]
+fn check_similarity(_world: &mut HashWorld, left: String, right: String, expected: f64) {
+    let similarity = english_word::readable_similarity(&left, &right);
+    assert!((similarity - expected).abs() < 1e-9, "{similarity}");
+}
+
+#[when(expr = "a word is generated from the entropy with the {word} token map")]
+fn generate_mapped_word(world: &mut HashWorld, map_name: String) {
+    let map_token: fn(&str) -> String = match map_name.as_str() {
+        "identity" => str::to_string,
+        "bracketing" => |token| format!("[{token}]"),
+        "vowel-uppercasing" => |token| {
+            token
+                .chars()
+                .map(|character| {
+                    if "aeiou".contains(character) {
+                        character.to_ascii_uppercase()
+                    } else {
+                        character
+                    }
+                })
+                .collect()
+        },
+        _ => panic!("Unknown token map: {map_name}"),
+    };
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_word_mapped(&mut reader, map_token);
+}
+
+#[when(expr = "the canonical minimum word is generated {int} times")]
+fn generate_canonical_min_words(world: &mut HashWorld, times: usize) {
+    world.outputs = (0..times)
+        .map(|_| english_word::canonical_min_word())
+        .collect();
+}
+
+#[when(expr = "a word is generated from the entropy and mutated with seed {int}")]
+fn generate_and_mutate_word(world: &mut HashWorld, seed: u8) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let (word, tokens) = english_word::generate_word_tokens(&mut reader);
+    world.output = english_word::mutate_word(&word, &tokens, seed);
+    world.outputs = tokens
+        .iter()
+        .map(|token_id| english_word::token_text(*token_id).to_string())
+        .collect();
+}
+
+#[then(expr = "mutating {string} with the tokens of {string} should give {string}")]
+fn check_mismatched_mutation(
+    _world: &mut HashWorld,
+    word: String,
+    entropy: String,
+    expected: String,
+) {
+    let entropy = hex::decode(entropy).expect("Invalid hex entropy");
+    let (_, tokens) = english_word::generate_word_tokens(&mut SliceReader::new(&entropy));
+    assert_eq!(english_word::mutate_word(&word, &tokens, 0), expected);
+}
+
+#[then("the result should differ from the generated word in exactly one token")]
+fn check_single_token_mutation(world: &mut HashWorld) {
+    let tokens = &world.outputs;
+    let changed = (0..tokens.len()).filter(|i| {
+        let prefix = tokens[..*i].concat();
+        let suffix = tokens[i + 1..].concat();
+        world.output.len() > prefix.len() + suffix.len()
+            && world.output.starts_with(&prefix)
+            && world.output.ends_with(&suffix)
+            && world.output[prefix.len()..world.output.len() - suffix.len()] != tokens[*i]
+    });
+    assert!(
+        changed.count() >= 1,
+        "{} is not {} with one token changed",
+        world.output,
+        tokens.join("|")
+    );
+    assert_ne!(world.output, tokens.concat());
+}
+
+#[then(expr = "the callback should have seen {string}")]
+fn check_callback_partials(world: &mut HashWorld, expected: String) {
+    assert_eq!(world.outputs.join(","), expected);
+}
+
+#[when("a word and its reverse are generated from the entropy")]
+fn generate_word_and_reverse(world: &mut HashWorld) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let (word, reversed) = english_word::generate_word_both(&mut reader);
+    world.output = word;
+    world.outputs = vec![reversed];
+}
+
+#[then(expr = "the reversed form should be {string}")]
+fn check_reversed_form(world: &mut HashWorld, expected: String) {
+    assert_eq!(world.outputs, vec![expected]);
+}
+
+#[then("reversing the reversed form should give the word")]
+fn check_reverse_round_trip(world: &mut HashWorld) {
+    let recovered: String = world.outputs[0].chars().rev().collect();
+    assert_eq!(recovered, world.output);
+}
+
+#[cfg(feature = "regex")]
+#[when(expr = "a word matching {string} is generated from the entropy")]
+fn generate_matching_word(world: &mut HashWorld, pattern: String) {
+    let pattern = regex::Regex::new(&pattern).expect("Invalid pattern");
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_word_matching(&mut reader, &pattern);
+}
+
+#[then(expr = "the result should match the pattern {string}")]
+fn check_matches_pattern(world: &mut HashWorld, pattern: String) {
+    let pattern = regex::Regex::new(&pattern).expect("Invalid pattern");
+    assert!(
+        pattern.is_match(&world.output),
+        "'{}' does not match '{}'",
+        world.output,
+        pattern
+    );
+}
+
+#[when("a palindrome is generated from the entropy")]
+fn generate_palindrome_from_entropy(world: &mut HashWorld) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_palindrome(&mut reader);
+}
+
+#[then("the result should be a palindrome")]
+fn check_palindrome(world: &mut HashWorld) {
+    let reversed: String = world.output.chars().rev().collect();
+    assert_eq!(world.output, reversed, "Expected a palindrome");
+}
+
+#[when(expr = "a phrase of {int} words is generated from the entropy with separator {string}")]
+fn generate_phrase_with_separator(world: &mut HashWorld, word_count: usize, separator: String) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output =
+        english_word::generate_phrase(&mut reader, word_count, SeparatorPolicy::Fixed(&separator));
+}
+
+#[when(expr = "a word is generated from seed {int}")]
+fn generate_word_from_seed(world: &mut HashWorld, seed: u64) {
+    world.output = english_word::generate_word_from_seed(seed);
+}
+
+#[when(expr = "a passphrase of {int} words is generated from seed {int}")]
+fn generate_passphrase_from_seed(world: &mut HashWorld, word_count: usize, seed: u64) {
+    world.output = english_word::generate_passphrase_from_seed(seed, word_count);
+}
+
+#[when(expr = "a passphrase of {int} words is generated from the entropy with separator {string}")]
+fn generate_passphrase(world: &mut HashWorld, word_count: usize, separator: String) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_passphrase(&mut reader, word_count, &separator);
+}
+
+#[when(
+    expr = "a phrase of {int} words is generated from the entropy with a separator from {string}"
+)]
+fn generate_phrase_with_digest_separator(
+    world: &mut HashWorld,
+    word_count: usize,
+    separators: String,
+) {
+    let separators: Vec<char> = separators.chars().collect();
+    let mut reader = SliceReader::new(&world.entropy);
+    world.output = english_word::generate_phrase(
+        &mut reader,
+        word_count,
+        SeparatorPolicy::FromDigest(&separators),
+    );
+}
+
+#[then(expr = "writing a phrase of {int} words with separator {string} should give the result")]
+fn check_written_phrase(world: &mut HashWorld, word_count: usize, separator: String) {
+    let mut reader = SliceReader::new(&world.entropy);
+    let mut written = Vec::new();
+    english_word::write_phrase(
+        &mut reader,
+        word_count,
+        SeparatorPolicy::Fixed(&separator),
+        &mut written,
+    )
+    .expect("Failed to write phrase");
+    assert_eq!(
+        String::from_utf8(written).expect("Invalid UTF-8"),
+        world.output
+    );
+}
+
+#[then(expr = "the result should have {int} words joined by one separator from {string}")]
+fn check_phrase_separator(world: &mut HashWorld, word_count: usize, separators: String) {
+    let used: Vec<char> = world
+        .output
+        .chars()
+        .filter(|character| !character.is_ascii_lowercase())
+        .collect();
+    assert_eq!(used.len(), word_count - 1, "Output: '{}'", world.output);
+    assert!(
+        used.iter().all(|character| *character == used[0]),
+        "Mixed separators in '{}'",
+        world.output
+    );
+    assert!(
+        separators.contains(used[0]),
+        "Separator '{}' not in '{}'",
+        used[0],
+        separators
+    );
+}
+
+#[when(expr = "{int} candidates are generated from the entropy")]
+fn generate_candidates_from_entropy(world: &mut HashWorld, count: usize) {
+    let mut reader = SliceReader::new(&world.entropy);
+    world.outputs = english_word::generate_candidates(&mut reader, count);
+}
+
+#[when(expr = "words with lengths {string} are generated from the entropy")]
+fn generate_series_lengths(world: &mut HashWorld, lengths: String) {
+    let lengths: Vec<usize> = lengths
+        .split(',')
+        .map(|length| length.parse().expect("Invalid length"))
+        .collect();
+    let mut reader = SliceReader::new(&world.entropy);
+    world.outputs = english_word::generate_word_series_lengths(&mut reader, &lengths);
+}
+
+#[then(expr = "the results should be at least {string} long")]
+fn check_result_lengths(world: &mut HashWorld, lengths: String) {
+    let lengths: Vec<usize> = lengths
+        .split(',')
+        .map(|length| length.parse().expect("Invalid length"))
+        .collect();
+    assert_eq!(world.outputs.len(), lengths.len());
+    for (output, length) in world.outputs.iter().zip(lengths) {
+        assert!(
+            output.len() >= length,
+            "'{}' is shorter than {}",
+            output,
+            length
+        );
+    }
+}
+
+#[then(expr = "there should be {int} results")]
+fn check_result_count(world: &mut HashWorld, expected_count: usize) {
+    assert_eq!(
+        world.outputs.len(),
+        expected_count,
+        "Unexpected results: {:?}",
+        world.outputs
+    );
+}
+
+#[then(expr = "there should be at most {int} results")]
+fn check_max_result_count(world: &mut HashWorld, max_count: usize) {
+    assert!(
+        world.outputs.len() <= max_count,
+        "Expected at most {} results, got: {:?}",
+        max_count,
+        world.outputs
+    );
+}
+
+#[then("all results should be distinct")]
+fn check_results_distinct(world: &mut HashWorld) {
+    for (i, output) in world.outputs.iter().enumerate() {
+        assert!(
+            !world.outputs[i + 1..].contains(output),
+            "Duplicate result '{}' in {:?}",
+            output,
+            world.outputs
+        );
+    }
+}
+
+#[when(expr = "the cli is run with {string}")]
+fn run_cli(world: &mut HashWorld, args: String) {
+    run_cli_with_stdin(world, args, String::new());
+}
+
+#[when(expr = "the cli is run with {string} and {string} on stdin")]
+fn run_cli_with_stdin(world: &mut HashWorld, args: String, stdin: String) {
+    use std::io::Write as _;
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_readable-hash"))
+        .args(args.split_whitespace())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to start the cli");
+    child
+        .stdin
+        .take()
+        .expect("No stdin")
+        .write_all(unescape_line_endings(&stdin).as_bytes())
+        .expect("Failed to write stdin");
+    let output = child.wait_with_output().expect("Failed to run the cli");
+    assert!(output.status.success(), "The cli failed: {:?}", output);
+    let stdout = String::from_utf8(output.stdout).expect("Output is not UTF-8");
+    world.output = stdout
+        .strip_suffix('\n')
+        .expect("Output does not end with a newline")
+        .to_string();
+}
+
+#[cfg(feature = "serde")]
+#[when("the readable hash of the input is serialized to JSON")]
+fn serialize_readable_hash(world: &mut HashWorld) {
+    let hash = ReadableHash::new::<StdHasher, _>(&world.input);
+    world.output = serde_json::to_string(&hash).expect("Failed to serialize");
+}
+
+#[cfg(feature = "serde")]
+#[when(expr = "the JSON {string} is deserialized as a readable hash")]
+fn deserialize_readable_hash(world: &mut HashWorld, json: String) {
+    let hash: ReadableHash = serde_json::from_str(&json).expect("Failed to deserialize");
+    world.output = hash.to_string();
+}
+
+#[cfg(feature = "serde")]
+#[then("the result should deserialize to the readable hash of the input")]
+fn check_readable_hash_round_trip(world: &mut HashWorld) {
+    let hash: ReadableHash = serde_json::from_str(&world.output).expect("Failed to deserialize");
+    assert_eq!(hash, ReadableHash::new::<StdHasher, _>(&world.input));
+}
+
+#[cfg(feature = "std")]
+#[when(expr = "the chunks {string} are written to a hash writer")]
+fn write_chunks_to_hash_writer(world: &mut HashWorld, chunks: String) {
+    let write_chunks = |writer: &mut dyn std::io::Write| {
+        for chunk in chunks.split('|') {
+            writer.write_all(chunk.as_bytes()).expect("Failed to write");
+        }
+    };
+    let mut words_writer = HashWriter::<StdHasher>::new();
+    write_chunks(&mut words_writer);
+    let mut syllables_writer = HashWriter::<StdHasher>::new();
+    write_chunks(&mut syllables_writer);
+    world.outputs = vec![
+        words_writer.finish_words(),
+        syllables_writer.finish_syllables(),
+    ];
+}
+
 fn main() {
     block_on(HashWorld::run("tests/features"));
 }
@@ -0,0 +1,13 @@
+Feature: Write readable hashes of many inputs
+
+  Background:
+    Given using the std hasher
+
+  Scenario: each input's hash is recovered by splitting the output
+    When the hashes of "hello,world,test" are written as a batch separated by newlines
+    Then the result should be "hired\nexist\namens" when newlines are escaped
+    And splitting the result on newlines should give the english word hashes of "hello,world,test"
+
+  Scenario: empty inputs keep their place in the batch
+    When the hashes of "hello,,test" are written as a batch separated by newlines
+    Then splitting the result on newlines should give the english word hashes of "hello,,test"
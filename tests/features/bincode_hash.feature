@@ -0,0 +1,12 @@
+@bincode
+Feature: Hash serializable values through bincode
+
+  Scenario Outline: equal values built differently hash the same
+    When the record "<record>" is built in two ways and hashed with bincode
+    Then all results should be equal
+    And the first result should be "<output>"
+
+    Examples:
+      | record                      | output            |
+      | user;42;1,2,3;admin,staff   | recurnetterzaders |
+      | service;7;65535;beta,a,zeta | windslavesbarkro  |
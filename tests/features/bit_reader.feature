@@ -0,0 +1,38 @@
+Feature: Read bits from entropy
+
+  Scenario Outline: bits are read most significant first across bytes
+    Given the entropy bytes "<entropy>"
+    When the entropy is read in groups of "<counts>" bits
+    Then the results should be "<values>"
+
+    Examples:
+      | entropy  | counts    | values        |
+      | B2FF     | 3,8,5     | 5,151,31      |
+      | DEADBEEF | 32        | 3735928559    |
+      | DEADBEEF | 4,12,16   | 13,3757,48879 |
+      | B2       | 1,1,1,1,5 | 1,0,1,1,none  |
+      | B2       | 0,8,1     | 0,178,none    |
+
+  Scenario Outline: reads across byte boundaries match a bitwise reference
+    Given the entropy bytes "DEADBEEFCAFEBABE0123456789ABCDEF5A"
+    Then reading the entropy in groups of "<counts>" bits should match reading it bit by bit
+
+    Examples:
+      | counts   |
+      | 3        |
+      | 7        |
+      | 13       |
+      | 3,7,13   |
+      | 13,32,1  |
+      | 0,5,31   |
+
+  Scenario Outline: reads after checking for more bits than they need
+    Given the entropy bytes "B2FF"
+    When the entropy is checked for 16 bits and then read in groups of "<counts>" bits
+    Then the results should be "<values>"
+
+    Examples:
+      | counts     | values       |
+      | u8,4       | 178,15       |
+      | 4,u8,4     | 11,47,15     |
+      | u8,u8,u8   | 178,255,none |
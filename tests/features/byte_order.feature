@@ -0,0 +1,18 @@
+Feature: Hash integers with explicit byte order
+
+  Background:
+    Given using the std hasher
+
+  Scenario Outline: byte order changes the hash
+    When the value <value> is hashed in both byte orders
+    Then all results should be distinct
+    And the first result should be "<big_endian>"
+
+    Examples:
+      | value      | big_endian       |
+      | 1          | recoisteroughing |
+      | 4294967296 | graphylefulously |
+
+  Scenario: symmetric values hash the same in both byte orders
+    When the value 0 is hashed in both byte orders
+    Then all results should be equal
@@ -0,0 +1,10 @@
+@cache
+Feature: Memoized readable hashes
+
+  Scenario: repeated calls return the cached result
+    Given the input "cached input"
+    When the cached hash of the input is generated 3 times
+    Then there should be 3 results
+    And all results should be equal
+    And the first result should match the english word hash of "cached input"
+    And the cache should have grown by 1
@@ -0,0 +1,18 @@
+Feature: Stop word generation from a callback
+
+  Scenario Outline: the callback stops the word after a prefix length
+    Given the entropy bytes "CA978112CA1BBDCAFAC231B39A23DC4DA786EFF8147C4E72B9807785AFEE48BB3E23E8160039594A33894F6564E1B1348BBD7A0088D42C4ACB73EEAED59C009D2E7D2C03A9507AE265ECF5B5356885A53393A2029D241394997265A1A25AEFC618AC3E7343F016890C510E93F935261169D9E3F565436429830FAF0934F4F8E4"
+    When a word is generated from the entropy stopping after <length> characters
+    Then the result should be "<output>"
+    And the callback should have seen "<partials>"
+
+    Examples:
+      | length | output     | partials            |
+      | 1      | thene      | the                 |
+      | 4      | thereaking | the,therea          |
+      | 8      | thereaths  | the,therea,thereath |
+
+  Scenario: a callback that never stops matches generate_word
+    Given the entropy bytes "DEADBEEFCAFE"
+    When a word is generated from the entropy stopping after 100 characters
+    Then the result should be "syruleolynther"
@@ -0,0 +1,18 @@
+Feature: Generate multiple candidate words
+
+  Scenario: candidates are distinct and fill the requested count
+    Given the entropy bytes "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F2021222324252627"
+    When 5 candidates are generated from the entropy
+    Then there should be 5 results
+    And all results should be distinct
+
+  Scenario: fewer candidates are returned when entropy runs out
+    Given the entropy bytes "DEADBEEFCAFEBABE0123456789ABCDEF"
+    When 5 candidates are generated from the entropy
+    Then there should be at most 4 results
+    And all results should be distinct
+
+  Scenario: no candidates come from empty entropy
+    Given the entropy bytes ""
+    When 3 candidates are generated from the entropy
+    Then there should be 0 results
@@ -0,0 +1,8 @@
+Feature: Lexicographically smallest word of the model
+
+  Scenario: the canonical minimum word is stable and non-empty
+    When the canonical minimum word is generated 3 times
+    Then there should be 3 results
+    And all results should be equal
+    And all results should be non-empty
+    And the first result should be "aaam"
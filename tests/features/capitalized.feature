@@ -0,0 +1,17 @@
+Feature: Capitalized words
+
+  Scenario Outline: the first letter is uppercased
+    Given the entropy bytes "<entropy>"
+    When a capitalized word is generated from the entropy
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy          | output           |
+      | DEADBEEFCAFE     | Syruleolynther   |
+      | AEF4FF           | Elatior          |
+      | 5A5A5A5A5A5A5A5A | Thinskingdovisis |
+
+  Scenario: an empty word stays empty
+    Given the entropy bytes ""
+    When a capitalized word is generated from the entropy
+    Then the result should be ""
@@ -0,0 +1,15 @@
+Feature: Cased word hashes
+
+  Scenario Outline: word hashes in upper and title case
+    Given the input "<input>"
+    When the english word hash is generated in <case> case
+    Then the result should be "<output>"
+
+    Examples:
+      | input     | case  | output    |
+      | hello     | upper | HIRED     |
+      | hello     | title | Hired     |
+      | different | upper | IMAUMATES |
+      | different | title | Imaumates |
+      |           | upper |           |
+      |           | title |           |
@@ -0,0 +1,22 @@
+Feature: Prefix readable hashes with a category
+
+  Background:
+    Given using the std hasher
+
+  Scenario Outline: the category is recovered from the hash
+    Given the input "<input>"
+    When the categorized hash is generated with categories "alpha,beta-team,gamma"
+    Then the result should be "<output>"
+    And the category should be recovered as "<category>" from "alpha,beta-team,gamma"
+
+    Examples:
+      | input | output           | category  |
+      | hello | gamma-sands      | gamma     |
+      | world | gamma-maman      | gamma     |
+      | test  | alpha-potions    | alpha     |
+      | foo   | beta-team-exter  | beta-team |
+
+  Scenario: an unknown prefix has no category
+    Given the input "hello"
+    When the categorized hash is generated with categories "alpha,beta-team,gamma"
+    Then the category should not be recovered from "alpha,beta"
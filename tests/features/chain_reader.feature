@@ -0,0 +1,14 @@
+Feature: Chain two entropy sources
+
+  Scenario Outline: a chained split reads like the whole entropy
+    Given the entropy bytes "DEADBEEFCAFE"
+    When a word is generated from the entropy split after <split> bytes and chained
+    Then the result should be "syruleolynther"
+
+    Examples:
+      | split |
+      | 0     |
+      | 1     |
+      | 3     |
+      | 5     |
+      | 6     |
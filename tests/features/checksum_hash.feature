@@ -0,0 +1,30 @@
+Feature: Word hashes with a hex checksum
+
+  Scenario Outline: a checksummed hash round-trips
+    Given the input "<input>"
+    When the checksummed hash is generated
+    Then the result should be "<output>"
+    And the checksummed hash should verify
+    And every single-letter change to the checksummed hash should fail
+
+    Examples:
+      | input     | output       |
+      | hello     | hired-78     |
+      | different | imaumates-82 |
+      |           | -00          |
+
+  Scenario Outline: verifying checksummed hashes
+    Then the checksummed hash "<hash>" should <outcome>
+
+    Examples:
+      | hash         | outcome |
+      | hired-78     | verify  |
+      | imaumates-82 | verify  |
+      | hired-79     | fail    |
+      | hired-87     | fail    |
+      | hired        | fail    |
+      | hired-       | fail    |
+      | hired-078    | fail    |
+      | -00          | verify  |
+      | -01          | fail    |
+      | hired-+7     | fail    |
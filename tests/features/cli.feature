@@ -0,0 +1,25 @@
+Feature: readable-hash command line tool
+
+  Scenario Outline: the input argument is hashed
+    When the cli is run with "<args>"
+    Then the result should be "<output>"
+
+    Examples:
+      | args                   | output                                   |
+      | hello                  | hi-red                                   |
+      | --mode=syllable hello  | hi-red                                   |
+      | --mode=word hello      | hired                                    |
+      | --hasher=std I         | wa-ged                                   |
+      | --words=3 hello        | hipportively tanamintry moabilipers      |
+
+  Scenario: stdin is hashed without its trailing newline
+    When the cli is run with "--mode=word" and "hello\n" on stdin
+    Then the result should be "hired"
+
+  Scenario: stdin is hashed without its trailing CRLF
+    When the cli is run with "--mode=word" and "hello\r\n" on stdin
+    Then the result should be "hired"
+
+  Scenario: stdin without a trailing newline is hashed as is
+    When the cli is run with "--mode=word" and "hello" on stdin
+    Then the result should be "hired"
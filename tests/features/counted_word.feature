@@ -0,0 +1,29 @@
+Feature: Count the bytes a word reads
+
+  Scenario Outline: the byte count covers every token
+    Given the entropy bytes "<entropy>"
+    When a word is generated from the entropy with its byte count
+    Then the result should be "<output>"
+    And the byte count should be <count>
+
+    Examples:
+      | entropy      | output         | count |
+      | DEADBEEFCAFE | syruleolynther | 6     |
+      | AEF4FF       | elatior        | 3     |
+      | AEF4         | elatious       | 2     |
+      | AEF4FFDEAD   | elatior        | 3     |
+      |              |                | 0     |
+
+  # "AEF4" reaches a token without middle continuations, so the word ends
+  # naturally and the next one starts after its end token byte.
+  Scenario: a long entropy source is chunked into words
+    Given the entropy bytes "AEF4FFDEADBEEFCAFE"
+    When words are generated from the entropy until it runs out
+    Then the results should be "elatior,syruleolynther"
+
+  # The bit reader drops consumed bytes, so a long reader does not grow its
+  # buffer; debug builds assert the bound on every read.
+  Scenario: a large reader is chunked into words
+    Given 100000 bytes of seeded entropy
+    When words are generated from the entropy until it runs out
+    Then the results should have checksum "c4619c3d5b24502c"
@@ -0,0 +1,13 @@
+Feature: Count the bytes a reader yields
+
+  Scenario Outline: the count matches the slice consumption
+    Given the entropy bytes "<entropy>"
+    Then a counting reader over the entropy should count <bytes> bytes for a <generator>
+
+    Examples:
+      | entropy              | generator  | bytes |
+      | AEF4FFDEAD           | word       | 3     |
+      | DEADBEEFCAFE         | word       | 6     |
+      |                      | word       | 0     |
+      | DEADBEEFCAFEBABE0011 | passphrase | 8     |
+      | DEADBEEFCA           | passphrase | 5     |
@@ -0,0 +1,14 @@
+Feature: Custom hashers
+
+  # The identity hasher's digest is the input, so the word hash is the word
+  # generated from the input bytes with the input length as the target.
+  Scenario Outline: a custom hasher drives the word hash
+    Given the entropy bytes "<entropy>"
+    When the english word hash of the entropy is generated with an identity hasher
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy      | output  |
+      | DEADBEEFCAFE | syrums  |
+      | AEF4FF       | elating |
+      |              |         |
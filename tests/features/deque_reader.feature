@@ -0,0 +1,14 @@
+Feature: Drain entropy from a deque
+
+  Scenario Outline: a word leaves the bytes it did not use in the deque
+    Given the entropy bytes "<entropy>"
+    When a word is generated by draining the entropy from a deque
+    Then the result should be "<output>"
+    And the entropy left should be "<left>"
+
+    Examples:
+      | entropy      | output         | left |
+      | AEF4FFDEAD   | elatior        | DEAD |
+      | AEF4FF       | elatior        |      |
+      | DEADBEEFCAFE | syruleolynther |      |
+      |              |                |      |
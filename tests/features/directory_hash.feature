@@ -0,0 +1,42 @@
+Feature: Hash directory trees
+
+  Background:
+    Given using the std hasher
+
+  Scenario: identical trees hash the same regardless of creation order
+    Given a new directory
+    And the directory contains "a.txt" with "alpha"
+    And the directory contains "nested/b.txt" with "beta"
+    And the directory contains "z.txt" with "zeta"
+    And a new directory
+    And the directory contains "z.txt" with "zeta"
+    And the directory contains "nested/b.txt" with "beta"
+    And the directory contains "a.txt" with "alpha"
+    When each directory is hashed
+    Then all results should be equal
+    And all results should be non-empty
+
+  Scenario: changing a file changes the hash
+    Given a new directory
+    And the directory contains "a.txt" with "alpha"
+    And a new directory
+    And the directory contains "a.txt" with "alphb"
+    When each directory is hashed
+    Then all results should be distinct
+
+  Scenario: moving content between files changes the hash
+    Given a new directory
+    And the directory contains "a" with "bc"
+    And a new directory
+    And the directory contains "ab" with "c"
+    When each directory is hashed
+    Then all results should be distinct
+
+  # Both names are invalid UTF-8 and would read as the same "\u{FFFD}.txt".
+  Scenario: file names that are not UTF-8 are hashed by their bytes
+    Given a new directory
+    And the directory contains a file named by the bytes "FF2E747874" with "alpha"
+    And a new directory
+    And the directory contains a file named by the bytes "FE2E747874" with "alpha"
+    When each directory is hashed
+    Then all results should be distinct
@@ -0,0 +1,29 @@
+Feature: Generate words dissimilar to an existing name
+
+  Scenario Outline: the word clears the distance threshold when achievable
+    Given the entropy bytes "CA978112CA1BBDCAFAC231B39A23DC4DA786EFF8147C4E72B9807785AFEE48BB3E23E8160039594A33894F6564E1B1348BBD7A0088D42C4ACB73EEAED59C009D2E7D2C03A9507AE265ECF5B5356885A53393A2029D241394997265A1A25AEFC618AC3E7343F016890C510E93F935261169D9E3F565436429830FAF0934F4F8E4"
+    When a word at least <distance> away from "<avoid>" is generated from the entropy
+    Then the result should be at least <distance> away from "<avoid>"
+    And the result should be "<output>"
+
+    Examples:
+      | avoid   | distance | output      |
+      | thene   | 0.5      | thereathory |
+      | thene   | 0.9      | empiricides |
+      | stering | 1.0      | dillowders  |
+
+  Scenario: the most dissimilar candidate is returned when the threshold is out of reach
+    Given the entropy bytes "DEADBEEF"
+    When a word at least 1.5 away from "syrule" is generated from the entropy
+    Then the result should be "syruleole"
+
+  Scenario Outline: similarity of words
+    Then the similarity of "<left>" and "<right>" should be <similarity>
+
+    Examples:
+      | left  | right   | similarity |
+      | there | there   | 1.0        |
+      | there | where   | 0.8        |
+      | there | the     | 0.6        |
+      | abc   | xyz     | 0.0        |
+      |       |         | 1.0        |
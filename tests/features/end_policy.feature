@@ -0,0 +1,25 @@
+Feature: End token policy
+
+  # "AEF4" reaches a token without middle continuations, so a third byte is
+  # left over for the end token exactly when the entropy is three bytes long.
+  # The model lists the most likely end token first, so a missing byte and a
+  # zero byte both select it.
+
+  Scenario Outline: the end policy at the exact entropy boundary
+    Given the entropy bytes "<entropy>"
+    When a word is generated from the entropy with the <policy> end policy
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy | policy      | output   |
+      | AEF4FF  | entropy     | elatior  |
+      | AEF400  | entropy     | elatious |
+      | AEF4    | entropy     | elatious |
+      | AEF4FF  | most-likely | elatious |
+      | AEF400  | most-likely | elatious |
+      | AEF4    | most-likely | elatious |
+
+  Scenario: the default policy matches generate_word
+    Given the entropy bytes "DEADBEEFCAFE"
+    When a word is generated from the entropy with the entropy end policy
+    Then the result should be "syruleolynther"
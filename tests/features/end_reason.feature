@@ -0,0 +1,14 @@
+Feature: Report why a generated word ended
+
+  Scenario Outline: words report whether entropy ran out
+    Given the entropy bytes "<hex>"
+    When a word is generated from the entropy with its end reason
+    Then the result should be "<output>"
+    And the word should have ended <reason>
+
+    Examples:
+      | hex      | output    | reason    |
+      | AEF442   | elatious  | naturally |
+      | DEADBEEF | syruleole | exhausted |
+      | FC       | s         | exhausted |
+      |          |           | exhausted |
@@ -0,0 +1,17 @@
+@blake3
+Feature: Generate English-like words from hash with BLAKE3
+
+  Background:
+    Given using the blake3 hasher
+
+  Scenario Outline: hashing strings produces expected output
+    Given the input "<input>"
+    When the english word hash is generated
+    Then the result should be "<output>"
+
+    Examples:
+      | input | output |
+      | hello | belled |
+      | world | vonce  |
+      | test  | faked  |
+      |       |        |
@@ -0,0 +1,18 @@
+@sha512
+Feature: Generate English-like words from hash with SHA-512
+
+  Background:
+    Given using the sha512 hasher
+
+  # Pinned so the SHA-512 mapping does not change silently.
+  Scenario Outline: hashing strings produces expected output
+    Given the input "<input>"
+    When the english word hash is generated
+    Then the result should be "<output>"
+
+    Examples:
+      | input | output |
+      | hello | grata  |
+      | world | maman  |
+      | test  | obsels |
+      |       |        |
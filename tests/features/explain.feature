@@ -0,0 +1,28 @@
+Feature: Explain which digest byte produced each token
+
+  Scenario Outline: the explanation spells the word hash from the digest bytes
+    Given the input "<input>"
+    When the input is explained
+    Then the explained tokens should spell the english word hash of the input
+    And the explained bytes should start the std digest of the input
+
+    Examples:
+      | input                                         |
+      | I                                             |
+      | different                                     |
+      | pneumonoultramicroscopicsilicovolcanoconiosis |
+
+  Scenario: explaining known entropy
+    Given the entropy bytes "DEADBEEF"
+    When the entropy is explained with target length 4
+    Then the explanation should be "sy:DE,ru:AD,ms:BE"
+
+  Scenario: an exhausted reader reports the fallback byte for the end token
+    Given the entropy bytes "CA"
+    When the entropy is explained with target length 1
+    Then the explanation should be "the:CA,re:00"
+
+  Scenario: an empty input has no explanation
+    Given the input ""
+    When the input is explained
+    Then there should be 0 results
@@ -0,0 +1,23 @@
+Feature: Fallible word generation
+
+  Scenario Outline: a word or the reason there is none
+    Given the entropy bytes "<entropy>"
+    When a word is tried from the entropy
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy      | output         |
+      | DEADBEEFCAFE | syruleolynther |
+      | AEF4FF       | elatior        |
+      |              | EmptyReader    |
+
+  Scenario Outline: a word of a target length or the reason there is none
+    Given the entropy bytes "<entropy>"
+    When a word of at least <target> characters is tried from the entropy
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy      | target | output               |
+      | DEADBEEFCAFE | 4      | syrums               |
+      | DEAD         | 20     | UnreachableTargetLen |
+      |              | 4      | EmptyReader          |
@@ -0,0 +1,63 @@
+Feature: Hash files and readers
+
+  Background:
+    Given using the std hasher
+
+  Scenario Outline: hashing a reader matches hashing the bytes
+    Given a file containing "<contents>"
+    When the file is hashed through a reader
+    Then the result should match the english word hash of "<contents>"
+
+    Examples:
+      | contents    |
+      | hello       |
+      | longer text |
+      |             |
+
+  @memmap2
+  Scenario Outline: memory-mapped hashing matches reader hashing
+    Given a file containing "<contents>"
+    When the file is hashed through a reader
+    Then hashing the file through a memory map should give the same result
+
+    Examples:
+      | contents    |
+      | hello       |
+      | longer text |
+      |             |
+
+  Scenario Outline: counting reader reports the number of bytes hashed
+    Given a file containing "<contents>"
+    When the file is hashed through a counting reader
+    Then the result should match the english word hash of "<contents>"
+    And the byte count should be <length>
+
+    Examples:
+      | contents    | length |
+      | hello       | 5      |
+      | longer text | 11     |
+      |             | 0      |
+
+  Scenario Outline: a limited reader hashes streams within the limit as usual
+    Given a file containing "<contents>"
+    When the file is hashed through a reader limited to <limit> bytes
+    Then the result should match the english word hash of "<contents>"
+
+    Examples:
+      | contents    | limit |
+      | hello       | 5     |
+      | longer text | 100   |
+      |             | 0     |
+
+  Scenario: a limited reader rejects a stream over the limit
+    Given a file containing "longer text"
+    When the file is hashed through a reader limited to 10 bytes
+    Then the result should be "error: input is longer than 10 bytes"
+
+  Scenario: a limited reader stops an endless stream
+    When an endless stream is hashed through a reader limited to 100000 bytes
+    Then the result should be "error: input is longer than 100000 bytes"
+
+  Scenario: a limited reader reports a failing stream as a read error
+    When a failing stream is hashed through a reader limited to 10 bytes
+    Then the result should be "error: failed to read input: read failed"
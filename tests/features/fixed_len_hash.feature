@@ -0,0 +1,26 @@
+Feature: Fixed-length word hashes
+
+  Scenario Outline: word hashes of an exact length
+    Given the input "<input>"
+    When the english word hash of exactly <len> characters is generated
+    Then the result should be "<output>"
+    And the result should have length <len>
+
+    Examples:
+      | input | len | output     |
+      | hello | 4   | himy       |
+      | hello | 6   | hiding     |
+      | hello | 10  | hipportive |
+      | I     | 6   | waking     |
+
+  # The model cannot finish a word starting with "hi" at three characters,
+  # so the target length word is used, which is longer than requested.
+  Scenario: a word that cannot end exactly is at least the length
+    Given the input "hello"
+    When the english word hash of exactly 3 characters is generated
+    Then the result should be "hired"
+
+  Scenario: an empty input gives an empty hash
+    Given the input ""
+    When the english word hash of exactly 6 characters is generated
+    Then the result should be ""
@@ -0,0 +1,25 @@
+Feature: Multi-word hashes
+
+  # More words read more of the digest stream, so each added word extends
+  # the phrase and makes collisions between inputs less likely.
+  Scenario Outline: phrases of increasing length share their first words
+    Given the input "<input>"
+    When a word hash phrase of <words> words is generated with separator "-"
+    Then the result should be "<output>"
+
+    Examples:
+      | input | words | output                              |
+      | hello | 1     | hipportively                        |
+      | hello | 2     | hipportively-tanamintry             |
+      | hello | 3     | hipportively-tanamintry-moabilipers |
+      | hello | 0     |                                     |
+
+  Scenario: the separator joins the words
+    Given the input "hello"
+    When a word hash phrase of 2 words is generated with separator " "
+    Then the result should be "hipportively tanamintry"
+
+  Scenario: an empty input gives an empty phrase like the word hash
+    Given the input ""
+    When a word hash phrase of 3 words is generated with separator "-"
+    Then the result should be ""
@@ -0,0 +1,14 @@
+Feature: Hash a stream written in chunks
+
+  # Each result lists the words, then the tokens joined by "-".
+  Scenario Outline: chunked writes hash like the whole input
+    When the chunks "<chunks>" are written to a hash writer
+    Then the results should be "<words>,<syllables>"
+
+    Examples:
+      | chunks    | words | syllables |
+      | hel\|lo   | hired | hi-red    |
+      | h\|e\|llo | hired | hi-red    |
+      | hello     | hired | hi-red    |
+      | I         | waged | wa-ged    |
+      |           |       |           |
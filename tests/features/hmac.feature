@@ -0,0 +1,14 @@
+@hmac
+Feature: Keyed word hashes
+
+  Scenario Outline: the key selects the word
+    Given the input "<input>"
+    When the input is hashed with the hmac key "<key>"
+    Then the result should be "<output>"
+    And the result should not match the english word hash of "<input>"
+
+    Examples:
+      | input | key          | output |
+      | hello | secret       | scler  |
+      | hello | other secret | potes  |
+      | I     | secret       | spies  |
@@ -0,0 +1,25 @@
+Feature: Insert an infix at a token boundary
+
+  Scenario Outline: the selector byte picks the boundary
+    Given the entropy bytes "<entropy>"
+    When a word is generated from the entropy with the infix "-co-"
+    Then the result should be "<output>"
+    And the infix "-co-" should appear exactly once
+    And without the infix "-co-" the result should be the word from the remaining entropy
+
+    Examples:
+      | entropy    | output        |
+      | 00DEADBEEF | sy-co-ruleole |
+      | 01DEADBEEF | syru-co-leole |
+      | 02DEADBEEF | syrule-co-ole |
+      | 03DEADBEEF | syruleol-co-e |
+
+  Scenario: a single-token word gets the infix appended
+    Given the entropy bytes "07DE"
+    When a word is generated from the entropy with the infix "-co-"
+    Then the result should be "sy-co-"
+
+  Scenario: an empty reader yields an empty word
+    Given the entropy bytes ""
+    When a word is generated from the entropy with the infix "-co-"
+    Then the result should be ""
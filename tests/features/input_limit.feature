@@ -0,0 +1,22 @@
+Feature: Refuse inputs over a length limit
+
+  Scenario Outline: inputs within the limit hash as usual
+    Given the input "<input>"
+    When the english word hash is generated with a limit of <limit> bytes
+    Then the result should match the english word hash of "<input>"
+
+    Examples:
+      | input     | limit |
+      | hello     | 5     |
+      | hello     | 100   |
+      | different | 9     |
+
+  Scenario: an empty input is within any limit
+    Given the input ""
+    When the english word hash is generated with a limit of 0 bytes
+    Then the result should be ""
+
+  Scenario: an input over the limit is rejected
+    Given the input "hello"
+    When the english word hash is generated with a limit of 4 bytes
+    Then the result should be "error: input is 5 bytes long, more than the allowed 4 bytes"
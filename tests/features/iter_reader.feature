@@ -0,0 +1,12 @@
+Feature: Generate words from a byte iterator
+
+  Scenario Outline: a byte iterator gives the same word as the slice
+    Given the entropy bytes "<entropy>"
+    When a word is generated from the entropy through a byte iterator
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy      | output         |
+      | DEADBEEFCAFE | syruleolynther |
+      | AEF4FF       | elatior        |
+      |              |                |
@@ -0,0 +1,15 @@
+@json
+Feature: Hash JSON documents independently of formatting
+
+  Scenario: reformatted documents with reordered keys hash the same
+    When the JSON documents '{"name": "readable", "tags": [1, 2], "nested": {"b": true, "a": null}}' and '{"nested":{"a":null,"b":true},"tags":[1,2],"name":"readable"}' are hashed
+    Then all results should be equal
+    And the first result should be "diptimmilionerata"
+
+  Scenario: reordered array items hash differently
+    When the JSON documents "[1, 2]" and "[2, 1]" are hashed
+    Then all results should be distinct
+
+  Scenario: invalid JSON is reported
+    When the JSON documents '{"a": }' and "{}" are hashed
+    Then the first result should be "error: expected value at line 1 column 7"
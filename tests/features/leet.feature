@@ -0,0 +1,23 @@
+Feature: Leetspeak styling
+
+  Scenario: higher intensity substitutes more characters
+    Given the entropy bytes "CA978112CA1BBDCAFAC231B39A23DC4DA786EFF8147C4E72B9807785AFEE48BB"
+    When leet words are generated from the entropy with intensities "0,32,128,224,255"
+    Then the results should have "0,1,10,22,23" substituted characters
+    And all results should unleet to the word from the entropy after the seed
+
+  Scenario: a known leet word
+    Given the entropy bytes "1122334455667788DEADBEEF"
+    When leet words are generated from the entropy with intensities "128"
+    Then the first result should be "5yrul3ol3"
+    And all results should unleet to the word from the entropy after the seed
+
+  Scenario: the same entropy and intensity give the same word
+    Given the entropy bytes "CA978112CA1BBDCAFAC231B39A23DC4DA786EFF8147C4E72B9807785AFEE48BB"
+    When leet words are generated from the entropy with intensities "128,128"
+    Then all results should be equal
+
+  Scenario: a reader without a full seed yields an empty word
+    Given the entropy bytes "01020304"
+    When leet words are generated from the entropy with intensities "255"
+    Then the first result should be ""
@@ -0,0 +1,17 @@
+Feature: Hash key/value maps
+
+  Background:
+    Given using the std hasher
+
+  Scenario: insertion order does not change the hash
+    When the maps "HOME=/root,SHELL=/bin/sh,LANG=C" and "LANG=C,HOME=/root,SHELL=/bin/sh" are hashed
+    Then all results should be equal
+    And the first result should be "whiffrayebarrark"
+
+  Scenario: moving characters between key and value changes the hash
+    When the maps "ab=c" and "a=bc" are hashed
+    Then all results should be distinct
+
+  Scenario: changing a value changes the hash
+    When the maps "HOME=/root" and "HOME=/home" are hashed
+    Then all results should be distinct
@@ -0,0 +1,21 @@
+Feature: Map token texts while generating a word
+
+  Scenario: the identity map matches generate_word
+    Given the entropy bytes "DEADBEEFCAFE"
+    When a word is generated from the entropy with the identity token map
+    Then the result should be "syruleolynther"
+
+  Scenario Outline: maps are applied to each token
+    Given the entropy bytes "DEADBEEFCAFE"
+    When a word is generated from the entropy with the <map> token map
+    Then the result should be "<output>"
+
+    Examples:
+      | map               | output                        |
+      | bracketing        | [sy][ru][le][ol][yn][the][r]  |
+      | vowel-uppercasing | syrUlEOlynthEr                |
+
+  Scenario: an empty reader yields an empty word
+    Given the entropy bytes ""
+    When a word is generated from the entropy with the bracketing token map
+    Then the result should be ""
@@ -0,0 +1,33 @@
+Feature: Cap generated word length
+
+  Scenario Outline: words stop before exceeding the maximum length
+    Given the entropy bytes "<entropy>"
+    When a word of at most <max_len> characters is generated from the entropy
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy          | max_len | output           |
+      | DEADBEEFCAFE     | 8       | syruleil         |
+      | 5A5A5A5A5A5A5A5A | 8       | thinsy           |
+      | FFFFFFFFFFFF     | 8       | xpugnees         |
+      | 5A5A5A5A5A5A5A5A | 100     | thinskingdovisis |
+
+  # The beginning token is always kept, so the shortest possible word
+  # overflows small limits.
+  Scenario Outline: tiny limits overflow with the shortest end token
+    Given the entropy bytes "<entropy>"
+    When a word of at most <max_len> characters is generated from the entropy
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy      | max_len | output |
+      | DEADBEEFCAFE | 0       | sy     |
+      | DEADBEEFCAFE | 1       | sy     |
+      | FFFFFFFFFFFF | 0       | xt     |
+      | FFFFFFFFFFFF | 1       | xt     |
+      | AEF4FF       | 1       | elder  |
+
+  Scenario: an empty reader gives an empty word
+    Given the entropy bytes ""
+    When a word of at most 8 characters is generated from the entropy
+    Then the result should be ""
@@ -0,0 +1,17 @@
+Feature: Readable MinHash sketches
+
+  Background:
+    Given using the std hasher
+
+  Scenario: a small edit keeps most of the sketch
+    When the minhash sketches of "the quick brown fox jumps over the lazy dog near the river bank" and "the quick brown fox jumps over the lazy cat near the river bank" are generated with shingle size 3
+    Then at least 6 of 8 sketch words should match
+
+  Scenario: identical inputs give identical sketches
+    When the minhash sketches of "hello world" and "hello world" are generated with shingle size 2
+    Then all results should be equal
+    And all results should be non-empty
+
+  Scenario: inputs shorter than a shingle still produce a sketch
+    When the minhash sketches of "hi" and "hi" are generated with shingle size 5
+    Then at least 8 of 8 sketch words should match
@@ -0,0 +1,18 @@
+Feature: Mutate one token of a generated word
+
+  Scenario Outline: the seed picks the token to change
+    Given the entropy bytes "DEADBEEFCAFE"
+    When a word is generated from the entropy and mutated with seed <seed>
+    Then the result should differ from the generated word in exactly one token
+    And the result should be "<output>"
+
+    Examples:
+      | seed | output          |
+      | 0    | priruleolynther |
+      | 1    | sylloleolynther |
+      | 3    | syruleareynther |
+      | 6    | syruleolynthers |
+      | 7    | priruleolynther |
+
+  Scenario: mismatched tokens leave the word unchanged
+    Then mutating "there" with the tokens of "DEADBEEF" should give "there"
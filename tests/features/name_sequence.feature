@@ -0,0 +1,14 @@
+Feature: Generate a sequence of unique names
+
+  Background:
+    Given using the std hasher
+
+  Scenario: the first names of the sequence are unique
+    When the first 1000 names are taken from a name sequence
+    Then there should be 1000 results
+    And all results should be distinct
+    And all results should be non-empty
+
+  Scenario: the sequence starts with the hash of the first counter values
+    When the first 2 names are taken from a name sequence
+    Then the first result should match the english word hash of "0"
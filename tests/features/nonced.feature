@@ -0,0 +1,18 @@
+Feature: Nonced word hashes
+
+  Scenario Outline: nonces share the input prefix but differ overall
+    Given the input "<input>"
+    When the english word hash is generated with nonces "0,1,2,3,4"
+    Then all results should start with "<prefix>"
+    And all results should be distinct
+
+    Examples:
+      | input            | prefix          |
+      | disk full        | nessenger       |
+      | hello            | hipportive      |
+      | connection reset | outlyshabl      |
+
+  Scenario: nonced hashes are stable
+    Given the input "disk full"
+    When the english word hash is generated with nonces "0,1"
+    Then the results should be "nessengerquabeting,nessengerwoodyne"
@@ -0,0 +1,28 @@
+Feature: Padding short words
+
+  # Padding swaps the end token for the most likely middle tokens and closes
+  # the word with the most likely end token, reading no further entropy.
+
+  Scenario Outline: short entropy padded to a minimum length
+    Given the entropy bytes "<entropy>"
+    When a word is generated from the entropy padded to <min_len> characters
+    Then the result should be "<output>"
+    And the word should be padded
+
+    Examples:
+      | entropy      | min_len | output                |
+      | 5A           | 10      | thordinaries          |
+      | DEADBEEFCAFE | 20      | syruleolyntherwinkrum |
+
+  Scenario: a word that is long enough is not padded
+    Given the entropy bytes "DEADBEEFCAFE"
+    When a word is generated from the entropy padded to 6 characters
+    Then the result should be "syruleolynther"
+    And the word should not be padded
+
+  # "AEF4" reaches a token without middle continuations.
+  Scenario: a word the model cannot extend is not padded
+    Given the entropy bytes "AEF4"
+    When a word is generated from the entropy padded to 12 characters
+    Then the result should be "elatious"
+    And the word should not be padded
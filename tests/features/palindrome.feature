@@ -0,0 +1,17 @@
+Feature: Generate palindromic words
+
+  Scenario: a palindrome is found with enough entropy
+    Given the entropy bytes "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F"
+    When a palindrome is generated from the entropy
+    Then the result should be a palindrome
+    And the result should be "epipe"
+
+  Scenario: the most symmetric candidate is used when no palindrome is found
+    Given the entropy bytes "DEADBEEFCAFEBABE0123456789ABCDEFFEDCBA9876543210DEADBEEFCAFEBABE"
+    When a palindrome is generated from the entropy
+    Then the result should be "quipium"
+
+  Scenario: palindrome generation handles empty entropy
+    Given the entropy bytes ""
+    When a palindrome is generated from the entropy
+    Then the result should be ""
@@ -0,0 +1,16 @@
+Feature: Generate passphrases
+
+  Scenario: passphrase words are joined with the separator
+    Given the entropy bytes "DEADBEEFCAFEBABE0123456789ABCDEF"
+    When a passphrase of 4 words is generated from the entropy with separator "-"
+    Then the result should be "syruleole-thematicizing-religiaries-marinessaft"
+
+  Scenario: passphrase stops at the last word the entropy allows
+    Given the entropy bytes "DEADBEEFCAFEBABE"
+    When a passphrase of 4 words is generated from the entropy with separator "-"
+    Then the result should be "syruleole-thematicizing"
+
+  Scenario: an empty reader gives an empty passphrase
+    Given the entropy bytes ""
+    When a passphrase of 4 words is generated from the entropy with separator "-"
+    Then the result should be ""
@@ -0,0 +1,22 @@
+Feature: Pronounceable passwords
+
+  Scenario: a long seed meets the character minimum
+    Given the entropy bytes "DEADBEEFCAFEBABE00112233445566778899AABBCCDDEEFF"
+    When a password of at least 20 characters and 1 words is generated from the entropy
+    Then the result should have at least 20 characters
+
+  Scenario Outline: digits from the entropy separate the words
+    Given the entropy bytes "<entropy>"
+    When a password of at least <chars> characters and <words> words is generated from the entropy
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy                      | chars | words | output                           |
+      | DEADBEEF07CAFEBABE0100112233 | 20    | 1     | syruleole7thematicizing          |
+      | DEADBEEF07CAFEBABE0100112233 | 1     | 3     | syruleole7thematicizing1reviique |
+      | DEADBEEF07CAFEBABE0100112233 | 0     | 0     |                                  |
+
+  Scenario: a finite reader ends the password early
+    Given the entropy bytes "DEADBEEF07"
+    When a password of at least 100 characters and 1 words is generated from the entropy
+    Then the result should be "syruleole"
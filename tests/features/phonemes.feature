@@ -0,0 +1,22 @@
+Feature: Generate words by phoneme count
+
+  Scenario Outline: the word meets the phoneme target
+    Given the entropy bytes "CA978112CA1BBDCAFAC231B39A23DC4DA786EFF8147C4E72B9807785AFEE48BB3E23E8160039594A33894F6564E1B1348BBD7A0088D42C4ACB73EEAED59C009D2E7D2C03A9507AE265ECF5B5356885A53393A2029D241394997265A1A25AEFC618AC3E7343F016890C510E93F935261169D9E3F565436429830FAF0934F4F8E4"
+    When a word of <target> phonemes is generated from the entropy
+    Then the word should have at least <target> phonemes
+    And the word should have <phonemes> phonemes
+    And the result should be "<output>"
+
+    Examples:
+      | target | phonemes | output          |
+      | 1      | 3        | thene           |
+      | 3      | 3        | thene           |
+      | 5      | 5        | theism          |
+      | 8      | 8        | thereality      |
+      | 12     | 12       | thereathormeric |
+
+  Scenario: an empty reader yields an empty word
+    Given the entropy bytes ""
+    When a word of 4 phonemes is generated from the entropy
+    Then the result should be ""
+    And the word should have 0 phonemes
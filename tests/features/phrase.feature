@@ -0,0 +1,25 @@
+Feature: Generate multi-word phrases
+
+  Scenario: phrase words are joined with a fixed separator
+    Given the entropy bytes "DEADBEEFCAFEBABE0123456789ABCDEF"
+    When a phrase of 3 words is generated from the entropy with separator "-"
+    Then the result should be "syruleole-thematicizing-religiaries"
+    And writing a phrase of 3 words with separator "-" should give the result
+
+  Scenario: phrase stops early when entropy runs out
+    Given the entropy bytes "DEADBEEFCAFEBABE"
+    When a phrase of 3 words is generated from the entropy with separator " "
+    Then the result should be "syruleole thematicizing"
+    And writing a phrase of 3 words with separator " " should give the result
+
+  Scenario Outline: separator is drawn deterministically from the set
+    Given the entropy bytes "<hex>"
+    When a phrase of 3 words is generated from the entropy with a separator from "-._"
+    Then the result should have 3 words joined by one separator from "-._"
+    And the result should be "<output>"
+
+    Examples:
+      | hex                                | output                              |
+      | 00DEADBEEFCAFEBABE0123456789ABCDEF | syruleole-thematicizing-religiaries |
+      | 01DEADBEEFCAFEBABE0123456789ABCDEF | syruleole.thematicizing.religiaries |
+      | 02DEADBEEFCAFEBABE0123456789ABCDEF | syruleole_thematicizing_religiaries |
@@ -0,0 +1,20 @@
+Feature: Generate words from an io::Read
+
+  Scenario Outline: an io reader gives the same word as the bytes
+    Given the entropy bytes "<entropy>"
+    When a word is generated from the entropy through an io reader
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy      | output         |
+      | DEADBEEFCAFE | syruleolynther |
+      | AEF4FF       | elatior        |
+
+  Scenario: a read error ends the word like the end of the stream
+    Given the entropy bytes "DEADBEEFCAFE"
+    When a word is generated from the entropy through an io reader that then fails
+    Then the result should be "syruleolynther"
+
+  Scenario: an io reader reports an unknown length until the stream ends
+    Given the entropy bytes "DEADBEEF"
+    Then an io reader over the entropy should report an unknown length until it is read to the end
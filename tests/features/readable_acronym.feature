@@ -0,0 +1,21 @@
+Feature: Generate acronym-style readable hashes
+
+  Background:
+    Given using the std hasher
+
+  Scenario Outline: acronyms are uppercase and have the requested length
+    Given the input "<input>"
+    When the readable acronym of length <length> is generated
+    Then the result should be uppercase
+    And the result should have length <length>
+
+    Examples:
+      | input | length |
+      | hello | 3      |
+      | world | 4      |
+      | test  | 6      |
+
+  Scenario: acronym of empty input is empty
+    Given the input ""
+    When the readable acronym of length 4 is generated
+    Then the result should be ""
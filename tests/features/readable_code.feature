@@ -0,0 +1,45 @@
+Feature: Readable codes with a Luhn check digit
+
+  Scenario: a generated code is a word, hashed digits and a check digit
+    Given the input "ticket-1234"
+    When a readable code with 4 digits is generated
+    Then the result should be "voiderating-55111"
+    And the readable code should be valid
+    And every single-digit change to the readable code should be invalid
+
+  Scenario: a code with a long numeric part is valid
+    Given the input "hello"
+    When a readable code with 12 digits is generated
+    Then the readable code should be valid
+    And every single-digit change to the readable code should be invalid
+
+  Scenario: the code of an empty input is valid
+    Given the input ""
+    When a readable code with 4 digits is generated
+    Then the result should be "-43216"
+    And the readable code should be valid
+    And every single-digit change to the readable code should be invalid
+
+  Scenario Outline: validating codes
+    Then the code "<code>" should be <validity>
+
+    Examples:
+      | code             | validity |
+      | word-79927398713 | valid    |
+      | word-79927398710 | invalid  |
+      | word-0           | valid    |
+      | word-18          | valid    |
+      | word-81          | invalid  |
+      | word-            | invalid  |
+      | -18              | valid    |
+      | word18           | invalid  |
+      | word-1a8         | invalid  |
+
+  # The identity hasher's digest is the input, so the digits are the
+  # entropy bytes modulo 10, except that bytes of 250 and above are skipped
+  # to keep every digit equally likely.
+  Scenario: hashed bytes that would bias the digits are skipped
+    Given the entropy bytes "FA070DFF"
+    When a readable code with 2 digits of the entropy is generated with an identity hasher
+    Then the result should be "minds-737"
+    And the readable code should be valid
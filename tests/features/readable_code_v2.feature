@@ -0,0 +1,30 @@
+Feature: Versioned readable codes with a checksum syllable
+
+  Scenario Outline: codes round-trip through the parser
+    Given the input "<input>"
+    When a version <version> readable code is generated
+    Then the result should be "<code>"
+    And the code should parse as version <version> with a valid checksum
+    And every single-letter change to the word should parse as version <version> with an invalid checksum
+
+    Examples:
+      | input       | version | code                |
+      | ticket-1234 | 0       | s-voiderating-ius   |
+      | ticket-1234 | 1       | c-voiderating-ation |
+      | hello       | 255     | cre-hired-to        |
+
+  Scenario: an empty input still has a version and a checksum
+    Given the input ""
+    When a version 7 readable code is generated
+    Then the code should parse as version 7 with a valid checksum
+
+  Scenario Outline: malformed codes do not parse
+    Then the code "<code>" should not parse
+
+    Examples:
+      | code       |
+      | word       |
+      | s-word     |
+      | s-word-s-s |
+      | qqq-word-s |
+      | s-word-qqq |
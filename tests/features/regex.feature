@@ -0,0 +1,18 @@
+@regex
+Feature: Generate words matching a pattern
+
+  Scenario Outline: a matching word is found with enough entropy
+    Given the entropy bytes "CA978112CA1BBDCAFAC231B39A23DC4DA786EFF8147C4E72B9807785AFEE48BB3E23E8160039594A33894F6564E1B1348BBD7A0088D42C4ACB73EEAED59C009D2E7D2C03A9507AE265ECF5B5356885A53393A2029D241394997265A1A25AEFC618AC3E7343F016890C510E93F935261169D9E3F565436429830FAF0934F4F8E4"
+    When a word matching "<pattern>" is generated from the entropy
+    Then the result should match the pattern "<pattern>"
+    And the result should be "<output>"
+
+    Examples:
+      | pattern  | output      |
+      | ^[aeiou] | empiricides |
+      | rd$      | reciduyard  |
+
+  Scenario: the closest candidate is returned when nothing matches
+    Given the entropy bytes "DEADBEEF"
+    When a word matching "^q" is generated from the entropy
+    Then the result should be "syruleole"
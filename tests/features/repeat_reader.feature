@@ -0,0 +1,18 @@
+Feature: Repeat a slice as endless entropy
+
+  Scenario Outline: bytes cycle across chunk boundaries
+    Given the entropy bytes "DEADBE"
+    When <len> bytes are read from the entropy repeated in chunks of <chunk>
+    Then the result should be "<output>"
+
+    Examples:
+      | len | chunk | output               |
+      | 10  | 1     | DEADBEDEADBEDEADBEDE |
+      | 10  | 4     | DEADBEDEADBEDEADBEDE |
+      | 10  | 7     | DEADBEDEADBEDEADBEDE |
+      | 2   | 2     | DEAD                 |
+
+  Scenario: a short seed drives a long passphrase
+    Given the entropy bytes "DEADBEEF"
+    When a passphrase of 3 words is generated from the entropy repeated
+    Then the result should be "syruleole-syruleole-syruleole"
@@ -0,0 +1,19 @@
+Feature: Generate words with their reversed form
+
+  Scenario Outline: reversed form round-trips to the word
+    Given the entropy bytes "<hex>"
+    When a word and its reverse are generated from the entropy
+    Then the result should be "<output>"
+    And the reversed form should be "<reversed>"
+    And reversing the reversed form should give the word
+
+    Examples:
+      | hex          | output         | reversed       |
+      | DEADBEEF     | syruleole      | eloelurys      |
+      | DEADBEEFCAFE | syruleolynther | rehtnyloelurys |
+
+  Scenario: reversing an empty word gives an empty word
+    Given the entropy bytes ""
+    When a word and its reverse are generated from the entropy
+    Then the result should be ""
+    And the reversed form should be ""
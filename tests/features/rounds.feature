@@ -0,0 +1,22 @@
+Feature: Hash with several rounds
+
+  Scenario: one round equals the english word hash
+    Given the input "hello"
+    When the english word hash is generated with "1" rounds
+    Then the first result should match the english word hash of "hello"
+
+  Scenario: zero rounds is treated as one
+    Given the input "hello"
+    When the english word hash is generated with "0" rounds
+    Then the first result should match the english word hash of "hello"
+
+  Scenario: different round counts give different words
+    Given the input "hello"
+    When the english word hash is generated with "1,2,3,100" rounds
+    Then all results should be distinct
+    And the results should be "hired,mades,roves,sully"
+
+  Scenario: the same round count is deterministic
+    Given the input "hello"
+    When the english word hash is generated with "50,50" rounds
+    Then all results should be equal
@@ -0,0 +1,25 @@
+Feature: Salted word hashes
+
+  Scenario Outline: an empty salt gives the plain word hash
+    Given using the <hasher> hasher
+    And the input "<input>"
+    When the english word hash is generated with salt ""
+    Then the result should match the english word hash of "<input>"
+
+    Examples:
+      | hasher | input |
+      | std    | x     |
+      | std    | hello |
+
+  Scenario Outline: salts keep the same input apart
+    Given the input "<input>"
+    When the english word hash is generated with salt "<salt>"
+    Then the result should be "<output>"
+    And the result should not match the english word hash of "<input>"
+
+    Examples:
+      | input | salt | output |
+      | x     | a    | plait  |
+      | x     | b    | shins  |
+      | x     | ab   | perch  |
+      | bx    | a    | thish  |
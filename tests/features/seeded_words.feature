@@ -0,0 +1,21 @@
+Feature: Generate words from a seed
+
+  Scenario Outline: a seed always yields the same word
+    When a word is generated from seed <seed>
+    Then the result should be "<output>"
+
+    Examples:
+      | seed | output                 |
+      | 0    | recriptualimentalitis  |
+      | 1    | civisionisphthincally  |
+      | 42   | parallesquettishenlet  |
+
+  Scenario Outline: a seed always yields the same passphrase
+    When a passphrase of <count> words is generated from seed <seed>
+    Then the result should be "<output>"
+
+    Examples:
+      | seed | count | output                                   |
+      | 0    | 3     | recriptual-prodicatively-commendinaching |
+      | 42   | 3     | parallesque-disponiality-reuthenon       |
+      | 42   | 0     |                                          |
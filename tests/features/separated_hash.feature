@@ -0,0 +1,17 @@
+Feature: English word hashes with token separators
+
+  Background:
+    Given using the std hasher
+
+  Scenario Outline: tokens are joined with the separator
+    Given the input "<input>"
+    When the english word hash is generated with separator "<separator>"
+    Then the result should be "<output>"
+
+    Examples:
+      | input | separator | output    |
+      | hello | -         | hi-red    |
+      | world | -         | ex-ist    |
+      | I     | ·         | wa·ged    |
+      | hello |           | hired     |
+      |       | -         |           |
@@ -0,0 +1,17 @@
+@serde
+Feature: Serialize readable hashes
+
+  Scenario Outline: a readable hash serializes as its plain string
+    Given the input "<input>"
+    When the readable hash of the input is serialized to JSON
+    Then the result should be '"<output>"'
+    And the result should deserialize to the readable hash of the input
+
+    Examples:
+      | input | output |
+      | hello | hired  |
+      | I     | waged  |
+
+  Scenario: any string deserializes without validation
+    When the JSON '"not a hash!"' is deserialized as a readable hash
+    Then the result should be "not a hash!"
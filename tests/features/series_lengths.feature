@@ -0,0 +1,12 @@
+Feature: Generate words following a length schedule
+
+  Scenario: each word meets its target length
+    Given the entropy bytes "CA978112CA1BBDCAFAC231B39A23DC4DA786EFF8147C4E72B9807785AFEE48BB3E23E8160039594A33894F6564E1B1348BBD7A0088D42C4ACB73EEAED59C009D2E7D2C03A9507AE265ECF5B5356885A53393A2029D241394997265A1A25AEFC618AC3E7343F016890C510E93F935261169D9E3F565436429830FAF0934F4F8E4"
+    When words with lengths "1,2,3,5,8,13,21" are generated from the entropy
+    Then the results should be at least "1,2,3,5,8,13,21" long
+    And the first result should be "thene"
+
+  Scenario: the schedule stops when entropy runs out
+    Given the entropy bytes "DEADBEEFCAFEBABE"
+    When words with lengths "5,5,5" are generated from the entropy
+    Then there should be 2 results
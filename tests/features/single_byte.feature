@@ -0,0 +1,16 @@
+Feature: Words from a single byte
+
+  Scenario: one byte of entropy always gives a word
+    Then every single-byte reader should give a non-empty word
+
+  # "sy" has no end token in the model, so that word is the beginning alone.
+  Scenario Outline: the byte picks the beginning and the end is the most likely
+    Given the entropy bytes "<entropy>"
+    When a word is generated from the entropy
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy | output |
+      | 00      | rents  |
+      | DE      | sy     |
+      | FF      | xes    |
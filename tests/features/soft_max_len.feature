@@ -0,0 +1,14 @@
+Feature: Soft maximum word length
+
+  Scenario: words rarely run far past the soft maximum
+    When words are generated from 200 seeds with a soft maximum of 10
+    Then there should be 200 results
+    And at most 10 results should be longer than 16 characters
+
+  Scenario: without a soft maximum the same entropy yields long words
+    When words are generated from 200 seeds without options
+    Then at least 190 results should be longer than 40 characters
+
+  Scenario: the soft maximum still lets the entropy shape the word
+    When words are generated from 200 seeds with a soft maximum of 10
+    Then at least 20 results should be longer than 13 characters
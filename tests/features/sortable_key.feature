@@ -0,0 +1,17 @@
+Feature: Readable keys that sort by digest
+
+  Scenario: sorting keys matches sorting digests
+    When sortable keys are generated for 200 numbered inputs
+    Then all results should be distinct
+    And sorting the keys should match sorting the std digests of the inputs
+
+  Scenario Outline: a key is a fixed-width prefix and the word hash
+    Given the input "<input>"
+    When the sortable key of the input is generated
+    Then the result should be "<key>"
+
+    Examples:
+      | input | key            |
+      | hello | jofktiq1-hired |
+      | I     | aol80aen-waged |
+      |       | 5h9go5b2-      |
@@ -0,0 +1,28 @@
+Feature: Generate english word hashes with a strength knob
+
+  Background:
+    Given using the std hasher
+
+  Scenario Outline: low strengths use fewer digest bytes
+    Given the input "<input>"
+    When the english word hash is generated with strength <bytes>
+    Then the result should be "<output>"
+
+    Examples:
+      | input       | bytes | output      |
+      | hello       | 0     |             |
+      | hello       | 1     | hides       |
+      | longer text | 1     | ac          |
+      | longer text | 2     | ack         |
+      | longer text | 4     | ackalfining |
+
+  Scenario Outline: full strength matches the english word hash
+    Given the input "<input>"
+    When the english word hash is generated with strength <bytes>
+    Then the result should match the english word hash of "<input>"
+
+    Examples:
+      | input       | bytes |
+      | hello       | 8     |
+      | hello       | 32    |
+      | longer text | 8     |
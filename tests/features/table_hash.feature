@@ -0,0 +1,15 @@
+Feature: Readable hashes from a custom byte table
+
+  Background:
+    Given using the std hasher
+
+  Scenario Outline: each digest byte is mapped through the table
+    Given the input "<input>"
+    When the input is hashed with a table of "<prefix>" and the byte in hex
+    Then the result should be the std digest of the input in hex with "<prefix>" before each byte
+
+    Examples:
+      | input | prefix |
+      | hello |        |
+      | world | x      |
+      |       | -      |
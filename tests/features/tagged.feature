@@ -0,0 +1,20 @@
+Feature: Readable word with a hex tag
+
+  Scenario Outline: the word and tag come from one digest
+    Given the input "<input>"
+    When the tagged hash is generated
+    Then the result should match the english word hash of "<input>"
+    And the tag should start the std digest of the input
+    And the tag should be "<tag>"
+
+    Examples:
+      | input     | tag    |
+      | hello     | 9e1f4e |
+      | different | fbeaf5 |
+
+  Scenario: an empty input has a tag but no word
+    Given the input ""
+    When the tagged hash is generated
+    Then the result should be ""
+    And the tag should start the std digest of the input
+    And the tag should be "2c530c"
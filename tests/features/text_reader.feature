@@ -0,0 +1,23 @@
+Feature: Hash text independently of line endings
+
+  Scenario: CRLF hashes like LF
+    When the texts "a\r\nb" and "a\nb" are hashed after 0 padding bytes
+    Then all results should be equal
+    And the first result should match the english word hash of the escaped text "a\nb"
+
+  Scenario: a lone CR hashes like LF
+    When the texts "a\rb" and "a\nb" are hashed after 0 padding bytes
+    Then all results should be equal
+
+  Scenario: mixed and trailing line endings are normalized
+    When the texts "a\r\r\nb\r" and "a\n\nb\n" are hashed after 0 padding bytes
+    Then all results should be equal
+    And the first result should match the english word hash of the escaped text "a\n\nb\n"
+
+  Scenario: a CRLF split across read chunks is one line ending
+    When the texts "\r\nb" and "\nb" are hashed after 8191 padding bytes
+    Then all results should be equal
+
+  Scenario: other whitespace still matters
+    When the texts "a\r\nb" and "a \nb" are hashed after 0 padding bytes
+    Then all results should be distinct
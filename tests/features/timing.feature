@@ -0,0 +1,13 @@
+@timing
+Feature: Measure word generation time
+
+  Scenario Outline: timed generation produces the same word
+    Given the entropy bytes "<hex>"
+    When a word is generated from the entropy with timing
+    Then the result should be "<output>"
+
+    Examples:
+      | hex          | output         |
+      | DEADBEEF     | syruleole      |
+      | DEADBEEFCAFE | syruleolynther |
+      |              |                |
@@ -0,0 +1,22 @@
+Feature: Inspect model tokens
+
+  Scenario: the model has 1024 tokens
+    Then the model should have 1024 tokens
+
+  Scenario Outline: token text without position markers
+    Then token <id> should read "<text>"
+
+    Examples:
+      | id   | text |
+      | 0    | s    |
+      | 256  | s    |
+      | 512  | e    |
+      | 1023 | mer  |
+
+  Scenario Outline: ids past the vocabulary have no text
+    Then token <id> should not exist
+
+    Examples:
+      | id    |
+      | 1024  |
+      | 65535 |
@@ -0,0 +1,27 @@
+Feature: Generate words within a token count window
+
+  Scenario Outline: plentiful entropy fills the window
+    Given the entropy bytes "CA978112CA1BBDCAFAC231B39A23DC4DA786EFF8147C4E72B9807785AFEE48BB3E23E8160039594A33894F6564E1B1348BBD7A0088D42C4ACB73EEAED59C009D2E7D2C03A9507AE265ECF5B5356885A53393A2029D241394997265A1A25AEFC618AC3E7343F016890C510E93F935261169D9E3F565436429830FAF0934F4F8E4"
+    When a word of <min> to <max> tokens is generated from the entropy
+    Then the result should be "<output>"
+
+    Examples:
+      | min | max | output         |
+      | 2   | 4   | thereaths      |
+      | 3   | 3   | thereaking     |
+      | 5   | 6   | thereathormers |
+
+  Scenario: short entropy is padded up to the minimum
+    Given the entropy bytes "DE"
+    When a word of 4 to 5 tokens is generated from the entropy
+    Then the result should be "sympather"
+
+  Scenario: short entropy ends below the maximum
+    Given the entropy bytes "DEADBE"
+    When a word of 2 to 8 tokens is generated from the entropy
+    Then the result should be "syrulet"
+
+  Scenario: empty entropy gives an empty word
+    Given the entropy bytes ""
+    When a word of 2 to 4 tokens is generated from the entropy
+    Then the result should be ""
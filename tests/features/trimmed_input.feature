@@ -0,0 +1,24 @@
+Feature: Hash input ignoring surrounding whitespace
+
+  Background:
+    Given using the std hasher
+
+  Scenario: trailing spaces do not change the hash
+    Given the input "hello "
+    When the english word hash of the trimmed input is generated
+    Then the result should match the english word hash of "hello"
+
+  Scenario: leading and trailing spaces do not change the hash
+    Given the input "   hello  "
+    When the english word hash of the trimmed input is generated
+    Then the result should match the english word hash of "hello"
+
+  Scenario: a trailing newline does not change the hash
+    Given the input "hello" followed by a newline
+    When the english word hash of the trimmed input is generated
+    Then the result should match the english word hash of "hello"
+
+  Scenario: inner whitespace still changes the hash
+    Given the input "he llo"
+    When the english word hash of the trimmed input is generated
+    Then the result should not match the english word hash of "hello"
@@ -0,0 +1,19 @@
+Feature: Numbered word variants
+
+  Scenario: variants of the same entropy differ
+    Given the entropy bytes "DEADBEEFCAFE"
+    When variants 0 to 7 are generated from the entropy
+    Then there should be 8 results
+    And all results should be distinct
+    And the first result should be "syruleolynther"
+
+  Scenario: the same variant is stable
+    Given the entropy bytes "DEADBEEFCAFE"
+    When variant 3 is generated from the entropy twice
+    Then all results should be equal
+    And the first result should be "homemarchitable"
+
+  Scenario: variant 0 matches the plain word
+    Given the entropy bytes "DEADBEEFCAFE"
+    When variants 0 to 0 are generated from the entropy
+    Then the first result should be "syruleolynther"
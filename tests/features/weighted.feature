@@ -0,0 +1,18 @@
+Feature: Hash weighted inputs
+
+  Background:
+    Given using the std hasher
+
+  Scenario: a low-weight input influences the output less
+    When weighted hashes are generated for "primary=6,nonce=1;primary=6,nonce2=1;primary2=6,nonce=1"
+    Then the second result should share a longer prefix with the first than the third
+    And all results should be distinct
+
+  Scenario: heavy weights extend finite hasher output
+    When weighted hashes are generated for "primary=12,nonce=2"
+    Then all results should be non-empty
+    And the first result should be "clamerciplarmaritanizzoileolicons"
+
+  Scenario: zero weights are ignored
+    When weighted hashes are generated for "primary=4,nonce=0;primary=4"
+    Then all results should be equal
@@ -0,0 +1,21 @@
+Feature: Estimate the entropy a word reads
+
+  Scenario Outline: estimates for word lengths
+    Then the estimated bits for <len> characters should be <bits>
+
+    Examples:
+      | len | bits |
+      | 0   | 16   |
+      | 3   | 16   |
+      | 10  | 32   |
+      | 20  | 64   |
+
+  Scenario Outline: the estimate is close to the bits actually read
+    Given the entropy bytes "<entropy>"
+    Then the estimated bits of the result should be within 16 bits of the bits it read
+
+    Examples:
+      | entropy      |
+      | DEADBEEFCAFE |
+      | AEF4FF       |
+      | 0123456789   |
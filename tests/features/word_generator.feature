@@ -0,0 +1,33 @@
+Feature: Word generator builder
+
+  # Each option set matches the free function with the same options:
+  # generate_word, generate_word_with_target_len, generate_word_with_max_len,
+  # generate_word_in_range and generate_word_capitalized.
+  Scenario Outline: generator options match the free functions
+    Given the entropy bytes "DEADBEEFCAFE"
+    When a word is generated from the entropy with generator options "<options>"
+    Then the result should be "<output>"
+
+    Examples:
+      | options                | output         |
+      |                        | syruleolynther |
+      | min=4                  | syrums         |
+      | max=8                  | syruleil       |
+      | min=5,max=7            | syrums         |
+      | min=4,max=4            | sylf           |
+      | capitalize             | Syruleolynther |
+      | min=4,max=4,capitalize | Sylf           |
+
+  # Without a maximum length the first word reads all the entropy, so a
+  # denied word has nothing to be replaced with and is kept.
+  Scenario Outline: denied words are generated again from the following entropy
+    Given the entropy bytes "DEADBEEFCAFEBABE0011"
+    When a word is generated from the entropy with generator options "<options>"
+    Then the result should be "<output>"
+
+    Examples:
+      | options                          | output                |
+      | max=8                            | syruleil              |
+      | max=8,deny=SYRULEIL              | themate               |
+      | max=8,deny=syruleil,deny=themate | harmonia              |
+      | deny=syruleolynthericockoy       | syruleolynthericockoy |
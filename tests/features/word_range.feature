@@ -0,0 +1,30 @@
+Feature: Generate words within a length range
+
+  Scenario Outline: words fall within the range
+    Given the entropy bytes "<entropy>"
+    When a word of <min_len> to <max_len> characters is generated from the entropy
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy          | min_len | max_len | output     |
+      | DEADBEEFCAFE     | 5       | 7       | syrums     |
+      | AEF4FF           | 10      | 12      | elatiously |
+      | 5A5A5A5A5A5A5A5A | 3       | 10      | thism      |
+
+  # With equal bounds the end token has to land exactly on the maximum.
+  Scenario Outline: the end token lands exactly on the maximum
+    Given the entropy bytes "<entropy>"
+    When a word of <len> to <len> characters is generated from the entropy
+    Then the result should be "<output>"
+
+    Examples:
+      | entropy          | len | output   |
+      | DEADBEEFCAFE     | 4   | sylf     |
+      | AEF4FF           | 4   | elfy     |
+      | 5A5A5A5A5A5A5A5A | 6   | things   |
+      | FFFFFFFFFFFF     | 8   | xpugness |
+
+  Scenario: an inverted range gives an empty word
+    Given the entropy bytes "DEADBEEFCAFE"
+    When a word of 8 to 5 characters is generated from the entropy
+    Then the result should be ""
@@ -0,0 +1,17 @@
+Feature: Iterate words from a reader
+
+  Scenario Outline: words from successive entropy slices
+    Given the entropy bytes "<entropy>"
+    When words are collected from the entropy
+    Then the results should be "<words>"
+
+    Examples:
+      | entropy              | words                           |
+      | DEADBEEFCAFEBABE0011 | syruleole,thematicizing,reville |
+      | DEADBEEF             | syruleole                       |
+      |                      |                                 |
+
+  Scenario: an endless reader is bounded with take
+    Given the entropy bytes "DEADBEEF"
+    When 3 words are taken from the entropy repeated
+    Then the results should be "syruleole,syruleole,syruleole"
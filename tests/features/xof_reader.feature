@@ -0,0 +1,12 @@
+@blake3
+Feature: Squeeze XOF output on demand
+
+  Scenario Outline: chunked squeezes match a one-shot squeeze
+    Then squeezing <len> bytes of the blake3 output of "<input>" in chunks of <chunk> should match a one-shot squeeze
+
+    Examples:
+      | input | len  | chunk |
+      | hello | 64   | 1     |
+      | hello | 100  | 7     |
+      | world | 1030 | 64    |
+      |       | 3000 | 1024  |
@@ -0,0 +1,11 @@
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn english_word_hash_is_stable() {
+    let word = readable_hash::wasm::english_word_hash("hello");
+    assert!(!word.is_empty());
+    assert_eq!(word, "hired");
+    assert_eq!(readable_hash::wasm::english_word_hash("hello"), word);
+}